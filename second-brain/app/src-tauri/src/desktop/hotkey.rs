@@ -26,6 +26,13 @@ pub struct WindowConfig {
     pub x: Option<i32>,
     pub y: Option<i32>,
     pub maximized: bool,
+    // Whether the window should stay visible across all virtual desktops /
+    // Spaces / workspaces (used by the spotlight-style quick popovers).
+    #[serde(default)]
+    pub visible_on_all_workspaces: bool,
+    // How the window's chrome is drawn; restored via the DECORATIONS state flag.
+    #[serde(default)]
+    pub decoration_mode: crate::desktop::window::DecorationMode,
 }
 
 impl Default for HotkeyConfig {
@@ -52,37 +59,101 @@ impl Default for WindowConfig {
             x: None,  // Always center, don't save position
             y: None,  // Always center, don't save position
             maximized: false,
+            visible_on_all_workspaces: false,
+            decoration_mode: crate::desktop::window::DecorationMode::default(),
         }
     }
 }
 
-// Helper function to get default window size (full HD resolution)
+// Fallback default window size used when no monitor information is available
+// (e.g. a `WindowConfig::default()` built without an `AppHandle`). The real
+// default is computed from the active monitor's full logical size (not its
+// work area) by `crate::desktop::window::default_main_window_size`.
 fn get_default_window_size() -> (f64, f64) {
-    // Use full HD as default window size
     (1920.0, 1080.0)
 }
 
+// Canonicalize a shortcut (or comma-separated chord sequence) into the stable
+// map-key form used by REGISTERED_SHORTCUTS. Each step is parsed into a
+// ParsedShortcut and serialized via `canonical()`, so equivalent spellings
+// collapse to one key; chord steps are joined with a single space to match the
+// whitespace-separated form the global-shortcut handler expects.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn canonical_shortcut(input: &str) -> Result<String, String> {
+    use crate::desktop::ParsedShortcut;
+
+    let steps: Vec<String> = input
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|step| step.parse::<ParsedShortcut>().map(|p| p.canonical()))
+        .collect::<Result<_, _>>()?;
+
+    if steps.is_empty() {
+        return Err(format!("No key found in shortcut: {}", input));
+    }
+    Ok(steps.join(" "))
+}
+
+// Shared conflict guard for every entry point that writes into
+// REGISTERED_SHORTCUTS (register_hotkey and the text-selection binding table):
+// refuse to clobber a shortcut already bound to a *different* command.
+// Re-binding the same command is idempotent.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+pub(crate) fn check_shortcut_conflict(shortcut: &str, command: &str) -> Result<(), String> {
+    let canonical = canonical_shortcut(shortcut)?;
+    let shortcuts = REGISTERED_SHORTCUTS.lock().unwrap();
+    if let Some(existing) = shortcuts.get(&canonical) {
+        if existing != command {
+            return Err(format!(
+                "Shortcut {} is already bound to command '{}'",
+                shortcut, existing
+            ));
+        }
+    }
+    Ok(())
+}
+
 #[tauri::command]
 pub fn register_hotkey(app: AppHandle, shortcut: String, command: String) -> Result<(), String> {
     #[cfg(not(any(target_os = "android", target_os = "ios")))]
     {
         use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
-        
-        // Parse the shortcut string
-        let parsed_shortcut = shortcut.parse::<Shortcut>()
+
+        // Canonicalize so equivalent spellings map to the same key. For a chord
+        // ("Alt+Space, N") the first step is the leader combo.
+        let canonical = canonical_shortcut(&shortcut)?;
+        let leader = shortcut
+            .split(',')
+            .next()
+            .map(str::trim)
+            .unwrap_or(&shortcut);
+
+        check_shortcut_conflict(&shortcut, &command)?;
+
+        // Only the leader combo is registered with the global-shortcut plugin;
+        // the chord's second step, if any, is matched against the raw key
+        // stream by `key_listener`/`setup::try_complete_chord` once the leader
+        // arms it.
+        let parsed_leader = leader.parse::<Shortcut>()
             .map_err(|e| format!("Invalid shortcut format: {}", e))?;
-        
-        // First try to unregister if it already exists (prevent duplicate registration)
-        let _ = app.global_shortcut().unregister(parsed_shortcut);
-        
-        // Register with Tauri global shortcut system
-        app.global_shortcut().register(parsed_shortcut)
+
+        // Unregister first so re-registering the same shortcut doesn't trip the
+        // plugin's "already registered" error.
+        let _ = app.global_shortcut().unregister(parsed_leader);
+        app.global_shortcut().register(parsed_leader)
             .map_err(|e| format!("Failed to register shortcut: {}", e))?;
-        
-        // Store command for the shortcut handler (normalize to lowercase)
+
         let mut shortcuts = REGISTERED_SHORTCUTS.lock().unwrap();
-        shortcuts.insert(shortcut.to_lowercase(), command.clone());
-        
+        shortcuts.insert(canonical, command.clone());
+
+        // Only a real chord needs the raw keyboard tap (to catch the suffix
+        // step, which has no OS registration of its own) — a single-combo
+        // shortcut is fully served by the plugin handler above.
+        if shortcut.contains(',') {
+            crate::desktop::key_listener::start(&app);
+        }
+
         println!("Successfully registered shortcut: {} for command: {}", shortcut, command);
         Ok(())
     }
@@ -97,19 +168,24 @@ pub fn unregister_hotkey(app: AppHandle, shortcut: String) -> Result<(), String>
     #[cfg(not(any(target_os = "android", target_os = "ios")))]
     {
         use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
-        
-        // Parse the shortcut string
-        let parsed_shortcut = shortcut.parse::<Shortcut>()
+
+        // Unregister the leader combo (the only step held by the plugin).
+        let leader = shortcut
+            .split(',')
+            .next()
+            .map(str::trim)
+            .unwrap_or(&shortcut);
+        let parsed_leader = leader.parse::<Shortcut>()
             .map_err(|e| format!("Invalid shortcut format: {}", e))?;
-        
-        // Unregister from Tauri global shortcut system
-        app.global_shortcut().unregister(parsed_shortcut)
+
+        app.global_shortcut().unregister(parsed_leader)
             .map_err(|e| format!("Failed to unregister shortcut: {}", e))?;
-        
-        // Remove from local storage (normalize to lowercase)
+
+        // Remove from local storage keyed by the canonical form.
+        let canonical = canonical_shortcut(&shortcut)?;
         let mut shortcuts = REGISTERED_SHORTCUTS.lock().unwrap();
-        shortcuts.remove(&shortcut.to_lowercase());
-        
+        shortcuts.remove(&canonical);
+
         println!("Successfully unregistered shortcut: {}", shortcut);
         Ok(())
     }
@@ -125,8 +201,26 @@ pub fn get_registered_shortcuts() -> HashMap<String, String> {
 }
 
 pub fn register_shortcut_command(shortcut: String, command: String) {
+    // Store under the canonical key so lookups match regardless of spelling;
+    // fall back to the raw string if it can't be parsed.
+    #[cfg(not(any(target_os = "android", target_os = "ios")))]
+    let key = canonical_shortcut(&shortcut).unwrap_or_else(|_| shortcut.to_lowercase());
+    #[cfg(any(target_os = "android", target_os = "ios"))]
+    let key = shortcut.to_lowercase();
+
     let mut shortcuts = REGISTERED_SHORTCUTS.lock().unwrap();
-    shortcuts.insert(shortcut.to_lowercase(), command);
+    shortcuts.insert(key, command);
+}
+
+/// Remove a command mapping stored via `register_shortcut_command`, keyed by the
+/// same canonical form so callers can pass the original accelerator string.
+pub fn unregister_shortcut_command(shortcut: &str) {
+    #[cfg(not(any(target_os = "android", target_os = "ios")))]
+    let key = canonical_shortcut(shortcut).unwrap_or_else(|_| shortcut.to_lowercase());
+    #[cfg(any(target_os = "android", target_os = "ios"))]
+    let key = shortcut.to_lowercase();
+
+    REGISTERED_SHORTCUTS.lock().unwrap().remove(&key);
 }
 
 #[allow(dead_code)]
@@ -142,9 +236,11 @@ pub fn setup_default_shortcuts(app_handle: &AppHandle) -> Result<(), String> {
             if let Err(e) = app_handle.global_shortcut().register(parsed_shortcut) {
                 eprintln!("Failed to register default quicknote hotkey: {}", e);
             } else {
-                // Store the registered shortcut (normalize to lowercase)
+                // Store the registered shortcut under its canonical key.
+                let key = canonical_shortcut(&default_config.quick_note)
+                    .unwrap_or_else(|_| default_config.quick_note.to_lowercase());
                 let mut shortcuts = REGISTERED_SHORTCUTS.lock().unwrap();
-                shortcuts.insert(default_config.quick_note.to_lowercase(), "quicknote".to_string());
+                shortcuts.insert(key, "quicknote".to_string());
                 println!("Registered default shortcut: {}", default_config.quick_note);
             }
         }
@@ -154,9 +250,11 @@ pub fn setup_default_shortcuts(app_handle: &AppHandle) -> Result<(), String> {
             if let Err(e) = app_handle.global_shortcut().register(parsed_shortcut) {
                 eprintln!("Failed to register default quickai hotkey: {}", e);
             } else {
-                // Store the registered shortcut (normalize to lowercase)
+                // Store the registered shortcut under its canonical key.
+                let key = canonical_shortcut(&default_config.quick_ai)
+                    .unwrap_or_else(|_| default_config.quick_ai.to_lowercase());
                 let mut shortcuts = REGISTERED_SHORTCUTS.lock().unwrap();
-                shortcuts.insert(default_config.quick_ai.to_lowercase(), "quickai".to_string());
+                shortcuts.insert(key, "quickai".to_string());
                 println!("Registered default AI shortcut: {}", default_config.quick_ai);
             }
         }
@@ -0,0 +1,135 @@
+//! A process-wide low-level keyboard tap used only for the two features that
+//! `tauri_plugin_global_shortcut` can't drive on its own: completing a chord's
+//! second step, and detecting a bare double-tap of a modifier key. The plugin
+//! only ever invokes its handler for accelerators registered with the OS, and
+//! neither a chord's suffix key nor a standalone modifier tap is ever
+//! registered that way — this listener is the key-event source both features
+//! were missing. Single-step shortcuts are unaffected; they keep dispatching
+//! through the existing plugin handler.
+//!
+//! Tapping every keystroke typed anywhere on the system is not something to
+//! do by default: `start` is only ever called lazily, from the two call
+//! sites that know a chord or DoubleTap binding actually exists
+//! (`register_hotkey` and `setup_text_selection_monitoring`). Nothing in this
+//! module calls it eagerly, and it must stay that way.
+#![cfg(not(any(target_os = "android", target_os = "ios")))]
+
+use rdev::{listen, Event, EventType, Key};
+use std::collections::HashSet;
+use std::sync::{LazyLock, Mutex, Once};
+use tauri::{AppHandle, Runtime};
+
+use crate::desktop::setup::{Modifiers, ParsedShortcut};
+
+// Modifiers currently held down, tracked live from the raw event stream so a
+// later non-modifier key-down can be combined with them into a full combo.
+static HELD_MODIFIERS: LazyLock<Mutex<Modifiers>> = LazyLock::new(|| Mutex::new(Modifiers::default()));
+
+// Keys currently held, used to tell an OS auto-repeat `KeyPress` apart from a
+// fresh one (rdev re-fires `KeyPress` for a held key with no intervening
+// `KeyRelease`).
+static HELD_KEYS: LazyLock<Mutex<HashSet<Key>>> = LazyLock::new(|| Mutex::new(HashSet::new()));
+
+static LISTENER_STARTED: Once = Once::new();
+
+// Map the subset of `rdev::Key` we care about (chord suffixes, common trigger
+// keys) to the lowercase token `ParsedShortcut::from_str` expects. Keys outside
+// this set simply aren't usable as a chord suffix; the OS-level accelerators
+// that rely on the plugin rather than this listener are unaffected.
+fn key_token(key: &Key) -> Option<&'static str> {
+    use Key::*;
+    Some(match key {
+        KeyA => "a", KeyB => "b", KeyC => "c", KeyD => "d", KeyE => "e",
+        KeyF => "f", KeyG => "g", KeyH => "h", KeyI => "i", KeyJ => "j",
+        KeyK => "k", KeyL => "l", KeyM => "m", KeyN => "n", KeyO => "o",
+        KeyP => "p", KeyQ => "q", KeyR => "r", KeyS => "s", KeyT => "t",
+        KeyU => "u", KeyV => "v", KeyW => "w", KeyX => "x", KeyY => "y", KeyZ => "z",
+        Num0 => "0", Num1 => "1", Num2 => "2", Num3 => "3", Num4 => "4",
+        Num5 => "5", Num6 => "6", Num7 => "7", Num8 => "8", Num9 => "9",
+        Space => "space",
+        BackQuote => "backquote",
+        Tab => "tab",
+        Escape => "escape",
+        Return => "enter",
+        _ => return None,
+    })
+}
+
+// The bare modifier name `TextSelectionMonitor`'s double-tap state machine
+// compares against ("ctrl", "alt", "shift", "meta"), or `None` for a non-modifier key.
+fn modifier_name(key: &Key) -> Option<&'static str> {
+    use Key::*;
+    match key {
+        ControlLeft | ControlRight => Some("ctrl"),
+        Alt | AltGr => Some("alt"),
+        ShiftLeft | ShiftRight => Some("shift"),
+        MetaLeft | MetaRight => Some("meta"),
+        _ => None,
+    }
+}
+
+fn modifier_bit(key: &Key) -> Option<Modifiers> {
+    use Key::*;
+    match key {
+        ControlLeft | ControlRight => Some(Modifiers::CTRL),
+        Alt | AltGr => Some(Modifiers::ALT),
+        ShiftLeft | ShiftRight => Some(Modifiers::SHIFT),
+        MetaLeft | MetaRight => Some(Modifiers::META),
+        _ => None,
+    }
+}
+
+fn handle_event<R: Runtime>(app: &AppHandle<R>, event: Event) {
+    match event.event_type {
+        EventType::KeyPress(key) => {
+            let is_repeat = !HELD_KEYS.lock().unwrap().insert(key);
+
+            if let Some(bit) = modifier_bit(&key) {
+                HELD_MODIFIERS.lock().unwrap().insert(bit);
+            }
+
+            // Every key-down feeds the double-tap machine: a bare press of the
+            // trigger modifier can complete a double-tap, anything else
+            // (including a different modifier) resets it.
+            crate::desktop::text_selection::process_key_down(app, modifier_name(&key), is_repeat);
+
+            // A non-modifier key-down, combined with whatever modifiers are
+            // currently held, is a candidate to complete a pending chord.
+            if let Some(token) = key_token(&key) {
+                let modifiers = *HELD_MODIFIERS.lock().unwrap();
+                let combo = ParsedShortcut { modifiers, key: token.to_string() };
+                crate::desktop::setup::try_complete_chord(app, &combo);
+            }
+        }
+        EventType::KeyRelease(key) => {
+            HELD_KEYS.lock().unwrap().remove(&key);
+            if let Some(bit) = modifier_bit(&key) {
+                HELD_MODIFIERS.lock().unwrap().remove(bit);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Start the background thread that taps the OS's raw keyboard stream.
+///
+/// This is a process-wide keyboard tap, not a feature some users opt into —
+/// it must only ever run for someone who actually configured a chord suffix
+/// or DoubleTap mode. Callers are expected to call this lazily, right after
+/// confirming that condition (`register_hotkey` for a comma-separated chord,
+/// `setup_text_selection_monitoring` for DoubleTap mode), never unconditionally
+/// at startup. Safe to call more than once, and from either call site — the
+/// `Once` guard means only the first call actually spawns the listener.
+/// Requires the same accessibility/input permission the app already asks for
+/// to read the selection via the accessibility API on macOS.
+pub fn start<R: Runtime>(app: &AppHandle<R>) {
+    LISTENER_STARTED.call_once(|| {
+        let app = app.clone();
+        std::thread::spawn(move || {
+            if let Err(e) = listen(move |event| handle_event(&app, event)) {
+                eprintln!("⚠️ Low-level key listener failed to start: {:?}", e);
+            }
+        });
+        println!("⌨️ Low-level key listener started (chord continuation + double-tap)");
+    });
+}
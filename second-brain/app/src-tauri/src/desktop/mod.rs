@@ -4,6 +4,8 @@ pub mod tray;
 pub mod setup;
 pub mod window_state;
 pub mod text_selection;
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+pub(crate) mod key_listener;
 
 pub use hotkey::*;
 pub use window::*;
@@ -21,10 +21,28 @@ pub fn setup_app(app: &mut tauri::App) -> Result<(), Box<dyn std::error::Error>>
         let _ = main_window.hide();
     } else {
         println!("Application launched normally");
+        // Size the main window as a fraction of the active monitor's work area
+        // before restoring; restore overrides this when a saved size exists.
+        if let Some((w, h)) = crate::desktop::window::default_main_window_size(&app_handle) {
+            let size = tauri::Size::Logical(tauri::LogicalSize::new(w, h));
+            if let Err(e) = main_window.set_size(size) {
+                eprintln!("Failed to apply default main window size: {}", e);
+            }
+            let _ = main_window.center();
+        }
         // Restore window state before applying decorations only for normal launches
         restore_main_window_state(&app_handle);
     }
 
+    // The main window is also built frameless, so give it a draggable overlay
+    // titlebar with native inset controls where the platform has them.
+    if let Err(e) = crate::desktop::window::install_overlay_titlebar(
+        &main_window,
+        crate::desktop::window::TitlebarStyle::OverlayInset,
+    ) {
+        eprintln!("Failed to install main window titlebar: {}", e);
+    }
+
     // Setup window state monitoring
     setup_window_state_monitoring(&app_handle);
 
@@ -58,170 +76,304 @@ pub fn setup_app(app: &mut tauri::App) -> Result<(), Box<dyn std::error::Error>>
         // This prevents conflicts between default and user-configured shortcuts
         println!("Waiting for frontend to register shortcuts based on user configuration...");
 
-        
+        // The low-level keyboard tap (`key_listener`) is intentionally *not*
+        // started here: it's a process-wide capture of every keystroke typed
+        // anywhere on the system, so it only starts lazily once `register_hotkey`
+        // or `setup_text_selection_monitoring` confirms a chord or DoubleTap
+        // binding actually exists to need it.
     }
 
     Ok(())
 }
 
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
-fn shortcuts_match(actual: &str, registered: &str) -> bool {
-    // Normalize both shortcuts for comparison
-    let normalize = |s: &str| -> String {
-        let mut normalized = s.to_lowercase();
-        
-        // Handle CommandOrControl -> control mapping
-        normalized = normalized.replace("commandorcontrol", "control");
-        
-        // Remove "key" prefix from key names (control+KeyG -> control+g)
-        normalized = normalized.replace("key", "");
-        
-        // Ensure consistent casing for modifiers
-        normalized = normalized.replace("shift+", "shift+");
-        normalized = normalized.replace("control+", "control+");
-        normalized = normalized.replace("alt+", "alt+");
-        normalized = normalized.replace("cmd+", "control+");
-        normalized = normalized.replace("command+", "control+");
-        
-        // Sort modifiers to ensure consistent order
-        let parts: Vec<&str> = normalized.split('+').collect();
-        if parts.len() > 1 {
-            let mut modifiers: Vec<&str> = parts[..parts.len()-1].to_vec();
-            let key = parts[parts.len()-1];
-            modifiers.sort();
-            format!("{}+{}", modifiers.join("+"), key)
-        } else {
-            normalized
+use std::collections::HashMap;
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+use std::str::FromStr;
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+use std::sync::{LazyLock, Mutex};
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+use std::time::{Duration, Instant};
+
+// How long a chord prefix stays armed while we wait for the next key.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+const CHORD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Keyboard modifiers as an order-independent bit set. Comparing on this instead
+/// of on reformatted strings removes a whole class of platform-representation
+/// bugs (e.g. `Control` vs `Super` vs `CommandOrControl`).
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Modifiers(u8);
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+impl Modifiers {
+    pub const CTRL: Self = Self(1 << 0);
+    pub const ALT: Self = Self(1 << 1);
+    pub const SHIFT: Self = Self(1 << 2);
+    pub const META: Self = Self(1 << 3);
+
+    pub(crate) fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+
+    pub(crate) fn remove(&mut self, other: Self) {
+        self.0 &= !other.0;
+    }
+}
+
+/// A shortcut parsed into a structured, canonical value. Equality is defined on
+/// the modifier set plus normalized key, so `CommandOrControl+Backquote`,
+/// `Control+\``, and `Grave+Control` all compare as equal regardless of the
+/// order the tokens were written in. `Cmd`/`Super` map to the separate Meta
+/// modifier, so `Cmd+\`` is a different shortcut from `Control+\``.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParsedShortcut {
+    pub modifiers: Modifiers,
+    pub key: String,
+}
+
+// Canonicalize a key token, unifying the many spellings of the backtick/grave
+// accent the OS global-shortcut layer hands back.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn normalize_key(token: &str) -> String {
+    match token {
+        "`" | "grave" | "backquote" => "backquote".to_string(),
+        // Strip the "key" prefix so `KeyG` and `g` unify.
+        other => other.strip_prefix("key").unwrap_or(other).to_string(),
+    }
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+impl Modifiers {
+    fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+impl ParsedShortcut {
+    /// Serialize to a stable canonical string that collapses equivalent
+    /// spellings to one value: the present modifiers encoded as the fixed
+    /// letters `A`/`S`/`C`/`M` (Alt/Shift/Ctrl/Meta, in that order), a `:`, then
+    /// the normalized key. So `Ctrl+Shift+Space` and `shift+ctrl+space` both
+    /// canonicalize to `SC:space`.
+    pub fn canonical(&self) -> String {
+        let mut letters = String::new();
+        if self.modifiers.contains(Modifiers::ALT) {
+            letters.push('A');
         }
-    };
-    
-    let normalized_actual = normalize(actual);
-    let normalized_registered = normalize(registered);
-    
-    println!("Shortcut match comparison: '{}' (from '{}') == '{}' (from '{}') -> {}", 
-             normalized_actual, actual, normalized_registered, registered,
-             normalized_actual == normalized_registered);
-    
-    normalized_actual == normalized_registered
+        if self.modifiers.contains(Modifiers::SHIFT) {
+            letters.push('S');
+        }
+        if self.modifiers.contains(Modifiers::CTRL) {
+            letters.push('C');
+        }
+        if self.modifiers.contains(Modifiers::META) {
+            letters.push('M');
+        }
+        format!("{}:{}", letters, self.key)
+    }
 }
 
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
-pub fn create_global_shortcut_handler() -> impl Fn(&AppHandle<tauri::Wry>, &tauri_plugin_global_shortcut::Shortcut, ShortcutEvent) + Send + Sync + 'static {
-    move |app, shortcut, event| {
-        if event.state == ShortcutState::Pressed {
-            let shortcut_str = shortcut.to_string();
-
-            println!("🔥 Global shortcut triggered: {}", shortcut_str);
-
-            // Check for text selection trigger combinations
-            // Handle different representations of backtick/grave accent
-            if shortcut_str.contains("Control") && (shortcut_str.contains("`") || shortcut_str.contains("Backquote") || shortcut_str.contains("Grave")) {
-                println!("🎹 Text selection trigger pressed: {} (ctrl + `)", shortcut_str);
-                let is_enabled = crate::desktop::is_text_selection_enabled_for("ctrl");
-                println!("🔍 Text selection enabled for ctrl: {}", is_enabled);
-                if is_enabled {
-                    println!("🚀 Triggering text selection via Ctrl + `");
-                    crate::desktop::handle_text_selection(app);
-                    return;
-                } else {
-                    println!("⚠️ Text selection not enabled for ctrl, ignoring shortcut");
-                }
-            } else if shortcut_str.contains("Shift") && (shortcut_str.contains("`") || shortcut_str.contains("Backquote") || shortcut_str.contains("Grave")) {
-                println!("🎹 Text selection trigger pressed: {} (shift + `)", shortcut_str);
-                let is_enabled = crate::desktop::is_text_selection_enabled_for("shift");
-                println!("🔍 Text selection enabled for shift: {}", is_enabled);
-                if is_enabled {
-                    println!("🚀 Triggering text selection via Shift + `");
-                    crate::desktop::handle_text_selection(app);
-                    return;
-                } else {
-                    println!("⚠️ Text selection not enabled for shift, ignoring shortcut");
-                }
-            } else if shortcut_str.contains("Alt") && (shortcut_str.contains("`") || shortcut_str.contains("Backquote") || shortcut_str.contains("Grave")) {
-                println!("🎹 Text selection trigger pressed: {} (alt + `)", shortcut_str);
-                let is_enabled = crate::desktop::is_text_selection_enabled_for("alt");
-                println!("🔍 Text selection enabled for alt: {}", is_enabled);
-                if is_enabled {
-                    println!("🚀 Triggering text selection via Alt + `");
-                    crate::desktop::handle_text_selection(app);
-                    return;
-                } else {
-                    println!("⚠️ Text selection not enabled for alt, ignoring shortcut");
+impl FromStr for ParsedShortcut {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Canonical form produced by `canonical()`: "<letters>:<key>".
+        if let Some((letters, key)) = s.split_once(':') {
+            let mut modifiers = Modifiers::default();
+            for ch in letters.chars() {
+                match ch {
+                    'A' => modifiers.insert(Modifiers::ALT),
+                    'S' => modifiers.insert(Modifiers::SHIFT),
+                    'C' => modifiers.insert(Modifiers::CTRL),
+                    'M' => modifiers.insert(Modifiers::META),
+                    other => return Err(format!("Invalid modifier letter: {}", other)),
                 }
             }
+            if key.is_empty() {
+                return Err(format!("No key found in shortcut: {}", s));
+            }
+            return Ok(ParsedShortcut { modifiers, key: normalize_key(key) });
+        }
 
-            // Get the command mapped to this shortcut from our registration map
-            let shortcuts_map = crate::desktop::get_registered_shortcuts();
-            println!("📋 Available shortcuts: {:?}", shortcuts_map);
-
-            // Try direct match first (normalize to lowercase)
-            if let Some(command) = shortcuts_map.get(&shortcut_str.to_lowercase()) {
-                println!("🎯 Direct match found: {} -> {}", shortcut_str, command);
-                match command.as_str() {
-                    "quicknote" => {
-                        let _ = toggle_quicknote_window(app.clone());
-                        println!("Triggered quicknote window via shortcut: {}", shortcut_str);
-                        return;
-                    },
-                    "quickai" => {
-                        let _ = toggle_quickai_window(app.clone());
-                        println!("Triggered quickai window via shortcut: {}", shortcut_str);
-                        return;
-                    },
-                    "quicktool" => {
-                        let _ = toggle_quicktool_window(app.clone());
-                        println!("Triggered quicktool window via shortcut: {}", shortcut_str);
-                        return;
-                    },
-                    "text-selection" => {
-                        println!("🚀 Triggering text selection via direct shortcut: {}", shortcut_str);
-                        crate::desktop::handle_text_selection(app);
-                        return;
-                    },
-                    _ => {
-                        println!("Unknown command for shortcut {}: {}", shortcut_str, command);
-                    }
-                }
-            } else {
-                println!("❌ No direct match for shortcut: {}", shortcut_str);
+        let mut modifiers = Modifiers::default();
+        let mut key: Option<String> = None;
+
+        for raw in s.split('+') {
+            let token = raw.trim().to_lowercase();
+            if token.is_empty() {
+                continue;
+            }
+            match token.as_str() {
+                "ctrl" | "control" | "commandorcontrol" | "cmdorctrl" => modifiers.insert(Modifiers::CTRL),
+                "alt" | "option" => modifiers.insert(Modifiers::ALT),
+                "shift" => modifiers.insert(Modifiers::SHIFT),
+                "cmd" | "command" | "super" | "meta" | "win" | "windows" => modifiers.insert(Modifiers::META),
+                other => key = Some(normalize_key(other)),
             }
+        }
 
-            // If no direct match, try to find by matching against all registered shortcuts
-            for (registered_shortcut, command) in shortcuts_map.iter() {
-                println!("🔍 Checking registered shortcut: '{}' -> '{}'", registered_shortcut, command);
-                if shortcuts_match(&shortcut_str, registered_shortcut) {
-                    println!("✅ Found matching shortcut: {} -> {}", shortcut_str, registered_shortcut);
-                    match command.as_str() {
-                        "quicknote" => {
-                            let _ = toggle_quicknote_window(app.clone());
-                            println!("Triggered quicknote window via matched shortcut: {} -> {}", shortcut_str, registered_shortcut);
-                            return;
-                        },
-                        "quickai" => {
-                            let _ = toggle_quickai_window(app.clone());
-                            println!("Triggered quickai window via matched shortcut: {} -> {}", shortcut_str, registered_shortcut);
-                            return;
-                        },
-                        "quicktool" => {
-                            let _ = toggle_quicktool_window(app.clone());
-                            println!("Triggered quicktool window via matched shortcut: {} -> {}", shortcut_str, registered_shortcut);
-                            return;
-                        },
-                        "text-selection" => {
-                            println!("🚀 Triggering text selection via matched shortcut: {} -> {}", shortcut_str, registered_shortcut);
-                            crate::desktop::handle_text_selection(app);
-                            return;
-                        },
-                        _ => {
-                            println!("⚠️ Unknown command '{}' for shortcut {}", command, registered_shortcut);
-                        }
-                    }
-                } else {
-                    println!("❌ No match for shortcut: {} vs {}", shortcut_str, registered_shortcut);
-                }
+        let key = key.ok_or_else(|| format!("No key found in shortcut: {}", s))?;
+        Ok(ParsedShortcut { modifiers, key })
+    }
+}
+
+/// An action a shortcut (or chord) can trigger.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    QuickNote,
+    QuickAi,
+    QuickTool,
+    ShowQuickTool,
+    TextSelection,
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+impl Action {
+    fn from_command(command: &str) -> Option<Self> {
+        match command {
+            "quicknote" => Some(Action::QuickNote),
+            "quickai" => Some(Action::QuickAi),
+            "quicktool" => Some(Action::QuickTool),
+            // Action name used by the text-selection binding table to pop the
+            // quicktool overlay directly.
+            "show-quicktool" => Some(Action::ShowQuickTool),
+            "text-selection" => Some(Action::TextSelection),
+            _ => None,
+        }
+    }
+}
+
+// A resolved binding: either a single combo or a two-step chord.
+//
+// The leader (`prefix`) is the only step ever registered with
+// `tauri_plugin_global_shortcut`, so it's what `create_global_shortcut_handler`
+// below receives. The `suffix` step has no OS registration of its own; it's
+// matched against the raw keyboard stream tapped by `key_listener`, which calls
+// `try_complete_chord` for every non-modifier key-down while a prefix is armed.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+struct Binding {
+    prefix: ParsedShortcut,
+    suffix: Option<ParsedShortcut>,
+    action: Action,
+}
+
+// Build the binding table from the registered-shortcut map. A registered key
+// containing a space (e.g. "Ctrl+K Ctrl+N") is treated as a chord sequence.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn build_bindings(map: &HashMap<String, String>) -> Vec<Binding> {
+    let mut bindings = Vec::new();
+    for (shortcut, command) in map {
+        let Some(action) = Action::from_command(command) else {
+            continue;
+        };
+        let mut steps = shortcut.split_whitespace();
+        let Some(prefix) = steps.next().and_then(|s| s.parse::<ParsedShortcut>().ok()) else {
+            continue;
+        };
+        let suffix = steps.next().and_then(|s| s.parse::<ParsedShortcut>().ok());
+        bindings.push(Binding { prefix, suffix, action });
+    }
+    bindings
+}
+
+// Pending chord prefix: set when the leader combo of a multi-key sequence is
+// pressed, cleared on timeout or once the sequence completes / mismatches.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+static PENDING_CHORD: LazyLock<Mutex<Option<(ParsedShortcut, Instant)>>> =
+    LazyLock::new(|| Mutex::new(None));
+
+// Called by `key_listener` for every non-modifier key-down, combined with
+// whatever modifiers are currently held. Completes a pending chord if `combo`
+// matches its armed prefix's suffix within `CHORD_TIMEOUT`. Any other key, or
+// one arriving after the timeout, disarms the pending prefix; with no chord
+// armed it's a no-op, so it's safe to call unconditionally on every keystroke.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+pub(crate) fn try_complete_chord<R: tauri::Runtime>(app: &AppHandle<R>, combo: &ParsedShortcut) {
+    let pending = {
+        let mut guard = PENDING_CHORD.lock().unwrap();
+        match guard.take() {
+            Some((prefix, at)) if at.elapsed() <= CHORD_TIMEOUT => Some(prefix),
+            _ => None,
+        }
+    };
+    let Some(prefix) = pending else {
+        return;
+    };
+
+    let bindings = build_bindings(&crate::desktop::get_registered_shortcuts());
+    for binding in &bindings {
+        if binding.prefix == prefix && binding.suffix.as_ref() == Some(combo) {
+            println!("🎹 Completed chord: {} {}", prefix.key, combo.key);
+            dispatch_action(app, binding.action);
+            return;
+        }
+    }
+    println!("↩️ Chord sequence reset (no matching continuation)");
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn dispatch_action<R: tauri::Runtime>(app: &AppHandle<R>, action: Action) {
+    match action {
+        Action::QuickNote => {
+            let _ = toggle_quicknote_window(app.clone());
+        }
+        Action::QuickAi => {
+            let _ = toggle_quickai_window(app.clone());
+        }
+        Action::QuickTool => {
+            let _ = toggle_quicktool_window(app.clone());
+        }
+        Action::ShowQuickTool => {
+            let _ = crate::desktop::show_quicktool(app.clone());
+        }
+        Action::TextSelection => {
+            crate::desktop::handle_text_selection(app);
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+pub fn create_global_shortcut_handler() -> impl Fn(&AppHandle<tauri::Wry>, &tauri_plugin_global_shortcut::Shortcut, ShortcutEvent) + Send + Sync + 'static {
+    move |app, shortcut, event| {
+        if event.state != ShortcutState::Pressed {
+            return;
+        }
+
+        let shortcut_str = shortcut.to_string();
+        let Ok(pressed) = shortcut_str.parse::<ParsedShortcut>() else {
+            println!("⚠️ Could not parse incoming shortcut: {}", shortcut_str);
+            return;
+        };
+
+        println!("🔥 Global shortcut triggered: {}", shortcut_str);
+
+        let bindings = build_bindings(&crate::desktop::get_registered_shortcuts());
+
+        // Linear scan over the structured bindings (rebuilt from the
+        // registered-shortcut map on every event, not a hashed lookup): first a
+        // single-combo match, then arming a chord prefix if one matches.
+        for binding in &bindings {
+            if binding.suffix.is_none() && binding.prefix == pressed {
+                println!("🎯 Matched shortcut: {} -> {:?}", shortcut_str, binding.action);
+                dispatch_action(app, binding.action);
+                return;
             }
+        }
 
-            println!("No command mapped for shortcut: {}", shortcut_str);
+        for binding in &bindings {
+            if binding.suffix.is_some() && binding.prefix == pressed {
+                println!("⏳ Chord prefix armed: {} (waiting for continuation)", pressed.key);
+                *PENDING_CHORD.lock().unwrap() = Some((pressed.clone(), Instant::now()));
+                return;
+            }
         }
+
+        println!("No command mapped for shortcut: {}", shortcut_str);
     }
 }
\ No newline at end of file
@@ -2,6 +2,12 @@ use tauri::{AppHandle, Emitter, Runtime, Manager};
 // Position and Size are not used in this file anymore
 use serde::{Deserialize, Serialize};
 use std::sync::{Mutex, LazyLock};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+// When enabled, the quicktool overlay is transparent to mouse events and is
+// shown without stealing keyboard focus.
+static QUICKTOOL_CLICK_THROUGH: AtomicBool = AtomicBool::new(false);
 
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
 use get_selected_text::get_selected_text;
@@ -48,10 +54,39 @@ pub struct TextSelectionEvent {
 // Global text selection monitoring state
 static TEXT_SELECTION_STATE: LazyLock<Mutex<TextSelectionMonitor>> = LazyLock::new(|| Mutex::new(TextSelectionMonitor::new()));
 
+/// How the text-selection capture is triggered.
+///
+/// * `Chord` fires on a full modifier+key accelerator (e.g. `Ctrl+\``).
+/// * `DoubleTap` fires on a quick double-tap of the bare trigger modifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TriggerMode {
+    #[default]
+    Chord,
+    DoubleTap,
+}
+
+// Default gap below which two taps of the trigger modifier count as a double-tap.
+const DEFAULT_DOUBLE_TAP_MS: u64 = 300;
+
+/// A single trigger binding: an accelerator (parsed into a global Shortcut) and
+/// the named action it dispatches ("text-selection", "show-quicktool", ...).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyBinding {
+    pub accelerator: String,
+    pub action: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct TextSelectionMonitor {
     pub enabled: bool,
     pub trigger_modifier: String,
+    pub trigger_mode: TriggerMode,
+    pub double_tap_threshold: Duration,
+    // Registered trigger bindings (terminal-emulator-style binding table).
+    pub bindings: Vec<KeyBinding>,
+    // Double-tap state machine.
+    last_modifier_press: Option<Instant>,
+    intervening_key: bool,
 }
 
 impl TextSelectionMonitor {
@@ -59,9 +94,132 @@ impl TextSelectionMonitor {
         Self {
             enabled: false,
             trigger_modifier: "ctrl".to_string(),
+            trigger_mode: TriggerMode::Chord,
+            double_tap_threshold: Duration::from_millis(DEFAULT_DOUBLE_TAP_MS),
+            bindings: Vec::new(),
+            last_modifier_press: None,
+            intervening_key: false,
+        }
+    }
+
+    /// Feed a key-down event into the double-tap state machine, returning true
+    /// when a double-tap of the trigger modifier is detected.
+    ///
+    /// The edge cases: any non-modifier keypress resets the machine so holding
+    /// a conversation with the keyboard never trips it, and auto-repeat
+    /// key-downs (`is_repeat`) are debounced so a held modifier doesn't register
+    /// as a second tap.
+    pub fn note_key_down(&mut self, is_trigger_modifier: bool, is_modifier: bool, is_repeat: bool) -> bool {
+        if !is_modifier {
+            // A real key was pressed in between; invalidate any pending tap.
+            self.last_modifier_press = None;
+            self.intervening_key = true;
+            return false;
+        }
+
+        // Ignore auto-repeat so a held-down modifier isn't seen as two taps.
+        if is_repeat {
+            return false;
+        }
+
+        if !is_trigger_modifier {
+            // A different modifier counts as intervening input.
+            self.intervening_key = true;
+            return false;
+        }
+
+        match self.last_modifier_press {
+            Some(prev) if prev.elapsed() <= self.double_tap_threshold && !self.intervening_key => {
+                // Second tap within the window with nothing in between.
+                self.last_modifier_press = None;
+                self.intervening_key = false;
+                true
+            }
+            _ => {
+                self.last_modifier_press = Some(Instant::now());
+                self.intervening_key = false;
+                false
+            }
         }
     }
+}
+
+// Register a single binding with the global-shortcut plugin and map its
+// accelerator to the named action for the global handler.
+fn register_binding<R: Runtime>(app: &AppHandle<R>, binding: &KeyBinding) -> Result<(), String> {
+    use tauri_plugin_global_shortcut::{Shortcut, GlobalShortcutExt};
+
+    let parsed: Shortcut = binding.accelerator.parse()
+        .map_err(|e| format!("Failed to parse accelerator '{}': {}", binding.accelerator, e))?;
 
+    // Refuse to steal a shortcut already bound to a different command through
+    // the register_hotkey path — same conflict guard, so either entry point
+    // into REGISTERED_SHORTCUTS enforces the invariant.
+    crate::desktop::hotkey::check_shortcut_conflict(&binding.accelerator, &binding.action)?;
+
+    // Avoid a duplicate-registration error if the same accelerator is re-added.
+    let _ = app.global_shortcut().unregister(parsed);
+    app.global_shortcut().register(parsed)
+        .map_err(|e| format!("Failed to register accelerator '{}': {}", binding.accelerator, e))?;
+
+    crate::desktop::register_shortcut_command(binding.accelerator.to_lowercase(), binding.action.clone());
+    println!("📝 Registered binding: {} -> {}", binding.accelerator, binding.action);
+    Ok(())
+}
+
+// Unregister every binding currently in the table and clear it.
+fn unregister_bindings<R: Runtime>(app: &AppHandle<R>, monitor: &mut TextSelectionMonitor) {
+    use tauri_plugin_global_shortcut::{Shortcut, GlobalShortcutExt};
+
+    for binding in monitor.bindings.drain(..) {
+        if let Ok(parsed) = binding.accelerator.parse::<Shortcut>() {
+            let _ = app.global_shortcut().unregister(parsed);
+        }
+        // Also drop the command mapping registered in register_binding so stale
+        // entries don't linger in REGISTERED_SHORTCUTS (tray/accelerator lookup).
+        crate::desktop::unregister_shortcut_command(&binding.accelerator);
+    }
+}
+
+#[tauri::command]
+pub fn add_text_selection_binding<R: Runtime>(
+    app: AppHandle<R>,
+    accelerator: String,
+    action: String,
+) -> Result<(), String> {
+    let binding = KeyBinding { accelerator, action };
+    register_binding(&app, &binding)?;
+
+    let mut monitor = TEXT_SELECTION_STATE.lock().unwrap();
+    // Drop an earlier entry for the same accelerator so it is listed once. A
+    // binding of it to a different action never gets here: register_binding's
+    // conflict check refuses it.
+    monitor.bindings.retain(|b| !b.accelerator.eq_ignore_ascii_case(&binding.accelerator));
+    monitor.bindings.push(binding);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn remove_text_selection_binding<R: Runtime>(
+    app: AppHandle<R>,
+    accelerator: String,
+) -> Result<(), String> {
+    use tauri_plugin_global_shortcut::{Shortcut, GlobalShortcutExt};
+
+    if let Ok(parsed) = accelerator.parse::<Shortcut>() {
+        let _ = app.global_shortcut().unregister(parsed);
+    }
+    crate::desktop::unregister_shortcut_command(&accelerator);
+
+    let mut monitor = TEXT_SELECTION_STATE.lock().unwrap();
+    monitor.bindings.retain(|b| !b.accelerator.eq_ignore_ascii_case(&accelerator));
+    println!("🗑️ Removed binding: {}", accelerator);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn list_text_selection_bindings() -> Vec<KeyBinding> {
+    TEXT_SELECTION_STATE.lock().unwrap().bindings.clone()
 }
 
 #[tauri::command]
@@ -69,55 +227,64 @@ pub fn setup_text_selection_monitoring<R: Runtime>(
     app: AppHandle<R>,
     enabled: bool,
     trigger_modifier: String,
+    trigger_mode: Option<String>,
+    double_tap_threshold_ms: Option<u64>,
 ) -> Result<(), String> {
-    use tauri_plugin_global_shortcut::{Shortcut, GlobalShortcutExt};
+    let mode = match trigger_mode.as_deref() {
+        Some("double-tap") | Some("doubletap") | Some("DoubleTap") => TriggerMode::DoubleTap,
+        _ => TriggerMode::Chord,
+    };
 
-    println!("🔧 setup_text_selection_monitoring called: enabled={}, modifier={}", enabled, trigger_modifier);
+    println!("🔧 setup_text_selection_monitoring called: enabled={}, modifier={}, mode={:?}",
+             enabled, trigger_modifier, mode);
 
     let mut monitor = TEXT_SELECTION_STATE.lock().unwrap();
+    monitor.trigger_mode = mode;
+    if let Some(ms) = double_tap_threshold_ms {
+        monitor.double_tap_threshold = Duration::from_millis(ms);
+    }
 
     if enabled {
         // Update the monitor state
         monitor.enabled = true;
         monitor.trigger_modifier = trigger_modifier.clone();
-
-        // Register the modifier key shortcut for text selection triggering
-        // Using Backquote which matches the actual shortcut string format
-        let shortcut_str = match trigger_modifier.as_str() {
-            "ctrl" => "Control+Backquote",
-            "shift" => "Shift+Backquote",
-            "alt" => "Alt+Backquote",
-            _ => "Control+Backquote",
-        };
-
-        println!("📝 Registering shortcut: {}", shortcut_str);
-
-        let parsed_shortcut: Shortcut = shortcut_str.parse()
-            .map_err(|e| format!("Failed to parse shortcut '{}': {}", shortcut_str, e))?;
-
-        app.global_shortcut().register(parsed_shortcut)
-            .map_err(|e| format!("Failed to register shortcut: {}", e))?;
-
-        // Store the shortcut mapping for the global handler (normalize to lowercase)
-        crate::desktop::register_shortcut_command(shortcut_str.to_lowercase(), "text-selection".to_string());
-
-        println!("✅ Text selection monitoring enabled with {} + Backquote", trigger_modifier);
+        // Reset the double-tap machine on (re)configuration.
+        monitor.last_modifier_press = None;
+        monitor.intervening_key = false;
+
+        // Start from a clean table so repeated setup calls don't leak bindings.
+        unregister_bindings(&app, &mut monitor);
+
+        // DoubleTap mode is driven by the key-event listener (note_key_down);
+        // only Chord mode needs a global accelerator registered.
+        if mode == TriggerMode::Chord {
+            // Seed the binding table with the default <modifier>+Backquote trigger.
+            let accelerator = match trigger_modifier.as_str() {
+                "ctrl" => "Control+Backquote",
+                "shift" => "Shift+Backquote",
+                "alt" => "Alt+Backquote",
+                _ => "Control+Backquote",
+            };
+            let binding = KeyBinding {
+                accelerator: accelerator.to_string(),
+                action: "text-selection".to_string(),
+            };
+            register_binding(&app, &binding)?;
+            monitor.bindings.push(binding);
+
+            println!("✅ Text selection monitoring enabled with {}", accelerator);
+        } else {
+            // DoubleTap has no OS accelerator to register; it's detected
+            // entirely from the raw keyboard tap, so this is the one place
+            // that needs to start it.
+            #[cfg(not(any(target_os = "android", target_os = "ios")))]
+            crate::desktop::key_listener::start(&app);
+            println!("✅ Text selection monitoring enabled with double-tap of {}", trigger_modifier);
+        }
     } else {
-        // Disable monitoring and unregister shortcuts
+        // Disable monitoring and unregister every binding in the table.
         monitor.enabled = false;
-
-        // Unregister the modifier shortcut
-        let shortcut_str = match monitor.trigger_modifier.as_str() {
-            "ctrl" => "Control+Backquote",
-            "shift" => "Shift+Backquote",
-            "alt" => "Alt+Backquote",
-            _ => "Control+Backquote",
-        };
-
-        if let Ok(parsed_shortcut) = shortcut_str.parse::<Shortcut>() {
-            let _ = app.global_shortcut().unregister(parsed_shortcut);
-        }
-
+        unregister_bindings(&app, &mut monitor);
         println!("❌ Text selection monitoring disabled");
     }
 
@@ -253,8 +420,16 @@ fn show_quicktool_window_at_position<R: Runtime>(app: &AppHandle<R>, x: f64, y:
         window.show()
             .map_err(|e| format!("Failed to show window: {}", e))?;
 
-        window.set_focus()
-            .map_err(|e| format!("Failed to focus window: {}", e))?;
+        // Apply click-through: transparent to mouse events, and keep keyboard
+        // focus on the underlying app by skipping set_focus.
+        let click_through = QUICKTOOL_CLICK_THROUGH.load(Ordering::SeqCst);
+        window.set_ignore_cursor_events(click_through)
+            .map_err(|e| format!("Failed to set click-through: {}", e))?;
+
+        if !click_through {
+            window.set_focus()
+                .map_err(|e| format!("Failed to focus window: {}", e))?;
+        }
 
         // Debug: Check if window is actually visible
         match window.is_visible() {
@@ -365,6 +540,27 @@ fn send_text_selection_event<R: Runtime>(app: &AppHandle<R>, text_event: &TextSe
     }
 }
 
+/// Entry point for a low-level key-down listener used by DoubleTap mode. Feed
+/// each key-down event here (with the bare modifier name, if the key is a
+/// modifier, and whether it's an auto-repeat); it triggers handle_text_selection
+/// when a double-tap of the configured trigger modifier is detected.
+pub fn process_key_down<R: Runtime>(app: &AppHandle<R>, modifier: Option<&str>, is_repeat: bool) {
+    let fire = {
+        let mut monitor = TEXT_SELECTION_STATE.lock().unwrap();
+        if !monitor.enabled || monitor.trigger_mode != TriggerMode::DoubleTap {
+            return;
+        }
+        let is_modifier = modifier.is_some();
+        let is_trigger = modifier == Some(monitor.trigger_modifier.as_str());
+        monitor.note_key_down(is_trigger, is_modifier, is_repeat)
+    };
+
+    if fire {
+        println!("🚀 Double-tap detected, triggering text selection");
+        handle_text_selection(app);
+    }
+}
+
 // Function to check if text selection is enabled for a modifier
 pub fn is_text_selection_enabled_for(modifier: &str) -> bool {
     println!("🔍 Checking if text selection is enabled for modifier: {}", modifier);
@@ -426,6 +622,22 @@ pub fn check_accessibility_permissions() -> Result<bool, String> {
     Ok(has_permissions)
 }
 
+#[tauri::command]
+pub fn set_quicktool_click_through<R: Runtime>(app: AppHandle<R>, enabled: bool) -> Result<(), String> {
+    QUICKTOOL_CLICK_THROUGH.store(enabled, Ordering::SeqCst);
+
+    // Toggle it immediately on the live window too (Windows uses the transparent
+    // extended window style, macOS toggles ignoresMouseEvents, Wayland sets an
+    // empty input region — all surfaced through Tauri's cursor-hittest API).
+    if let Some(window) = app.get_webview_window("quicktool") {
+        window.set_ignore_cursor_events(enabled)
+            .map_err(|e| format!("Failed to set click-through: {}", e))?;
+    }
+
+    println!("Set quicktool click-through: {}", enabled);
+    Ok(())
+}
+
 #[tauri::command]
 pub fn show_quicktool<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
     println!("🔧 Manually showing quicktool window");
@@ -454,36 +666,189 @@ pub fn show_quicktool<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
 }
 
 
+/// Where a selection was ultimately read from. Named in the log output so the
+/// chosen path is obvious.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SelectionSource {
+    Accessibility,
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    PrimarySelection,
+    ClipboardCopy,
+}
+
+// Read the X11/Wayland PRIMARY selection (the middle-click paste buffer) which
+// already holds the highlighted text, so we don't need the accessibility API or
+// a synthetic copy. Returns None when PRIMARY is empty or unavailable.
+#[cfg(target_os = "linux")]
+fn get_primary_selection() -> Option<String> {
+    use std::process::Command;
+
+    let is_wayland = std::env::var("XDG_SESSION_TYPE")
+        .map(|t| t.eq_ignore_ascii_case("wayland"))
+        .unwrap_or(false)
+        || std::env::var("WAYLAND_DISPLAY").is_ok();
+
+    let output = if is_wayland {
+        // Wayland: wl-clipboard exposes the primary-selection protocol.
+        Command::new("wl-paste").args(["--primary", "--no-newline"]).output()
+    } else {
+        // X11: read the PRIMARY selection buffer.
+        Command::new("xclip").args(["-selection", "primary", "-o"]).output()
+    };
+
+    match output {
+        Ok(out) if out.status.success() => {
+            let text = String::from_utf8_lossy(&out.stdout).to_string();
+            if text.trim().is_empty() {
+                None
+            } else {
+                println!("✅ Read PRIMARY selection (length: {})", text.len());
+                Some(text)
+            }
+        }
+        Ok(_) => None,
+        Err(e) => {
+            println!("⚠️ Failed to read PRIMARY selection: {}", e);
+            None
+        }
+    }
+}
+
 // Get selected text directly without using clipboard
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
 fn get_selected_text_directly() -> Result<String, String> {
     println!("📋 Attempting to get selected text directly...");
 
+    // On Linux the highlighted text is already in the PRIMARY selection; reading
+    // it is instantaneous and never clobbers the regular clipboard.
+    #[cfg(target_os = "linux")]
+    if let Some(text) = get_primary_selection() {
+        println!("📎 Selection source: {:?}", SelectionSource::PrimarySelection);
+        return Ok(text);
+    }
+
     // Check accessibility permissions on macOS
     if !query_accessibility_permissions() {
         println!("⚠️  Accessibility permissions not granted - text selection may not work properly");
         println!("ℹ️  On macOS, please grant accessibility permissions in System Settings > Privacy & Security > Accessibility");
     }
 
+    // Fast path: the accessibility API can read the selection directly.
     match get_selected_text() {
-        Ok(text) => {
-            if !text.trim().is_empty() {
-                println!("✅ Selected text found: '{}' (length: {})", text, text.len());
-                Ok(text)
-            } else {
-                println!("❌ Selected text is empty");
-                Err("No text selected".to_string())
-            }
+        Ok(text) if !text.trim().is_empty() => {
+            println!("✅ Selected text found via {:?}: '{}' (length: {})",
+                     SelectionSource::Accessibility, text, text.len());
+            return Ok(text);
+        }
+        Ok(_) => {
+            println!("❌ Selected text is empty");
         }
         Err(e) => {
-            println!("❌ Failed to get selected text: {}", e);
-            println!("ℹ️  This might be because:");
-            println!("   - The application doesn't support accessibility API");
-            println!("   - On macOS: accessibility permissions not granted");
-            println!("   - The fallback clipboard method will be used automatically");
-            Err(format!("Failed to get selected text: {}", e))
+            println!("❌ Accessibility read failed: {}", e);
+            println!("ℹ️  Falling back to the simulated-copy clipboard method");
         }
     }
+
+    // Fallback: simulate a copy keystroke and read the result off the clipboard,
+    // restoring the user's original clipboard afterwards.
+    get_selected_text_via_copy()
+}
+
+// RAII guard that restores the clipboard's original text when dropped. This is
+// the critical invariant of the simulated-copy fallback: the user's clipboard
+// must be put back to its prior value on every path, including the no-change /
+// timeout case.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+struct ClipboardRestore {
+    original: Option<String>,
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+impl Drop for ClipboardRestore {
+    fn drop(&mut self) {
+        if let Ok(mut clipboard) = Clipboard::new() {
+            match self.original.take() {
+                Some(text) => {
+                    if let Err(e) = clipboard.set_text(text) {
+                        eprintln!("Failed to restore clipboard contents: {}", e);
+                    } else {
+                        println!("♻️ Restored original clipboard contents");
+                    }
+                }
+                None => {
+                    // Nothing was there before; leave it cleared.
+                    let _ = clipboard.clear();
+                }
+            }
+        }
+    }
+}
+
+// Synthesize a Ctrl+C / Cmd+C keypress to the focused application.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn synthesize_copy() -> Result<(), String> {
+    use std::process::Command;
+
+    #[cfg(target_os = "macos")]
+    let result = Command::new("osascript")
+        .args(["-e", "tell application \"System Events\" to keystroke \"c\" using command down"])
+        .status();
+
+    #[cfg(target_os = "windows")]
+    let result = Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-Command",
+            "$wshell = New-Object -ComObject wscript.shell; $wshell.SendKeys('^c')",
+        ])
+        .status();
+
+    #[cfg(target_os = "linux")]
+    let result = {
+        // X11/X test route via xdotool; the middle-click PRIMARY buffer is read
+        // elsewhere, this covers the clipboard (CLIPBOARD) selection.
+        Command::new("xdotool")
+            .args(["key", "--clearmodifiers", "ctrl+c"])
+            .status()
+    };
+
+    match result {
+        Ok(status) if status.success() => Ok(()),
+        Ok(status) => Err(format!("Copy keystroke exited with status {}", status)),
+        Err(e) => Err(format!("Failed to synthesize copy keystroke: {}", e)),
+    }
+}
+
+// Snapshot the clipboard, simulate a copy, poll briefly for the contents to
+// change, and take the new text as the selection. The original clipboard is
+// always restored via ClipboardRestore's Drop impl.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn get_selected_text_via_copy() -> Result<String, String> {
+    use std::time::{Duration, Instant};
+
+    let mut clipboard = Clipboard::new()
+        .map_err(|e| format!("Failed to access clipboard: {}", e))?;
+
+    let original = clipboard.get_text().ok();
+    let _guard = ClipboardRestore { original: original.clone() };
+
+    synthesize_copy()?;
+
+    // Poll for up to ~200ms until the clipboard changes from the snapshot.
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_millis(200) {
+        std::thread::sleep(Duration::from_millis(20));
+        if let Ok(text) = clipboard.get_text() {
+            if !text.trim().is_empty() && original.as_deref() != Some(text.as_str()) {
+                println!("✅ Captured selection via {:?} (length: {})",
+                         SelectionSource::ClipboardCopy, text.len());
+                return Ok(text);
+            }
+        }
+    }
+
+    Err("Simulated copy produced no new selection".to_string())
 }
 
 
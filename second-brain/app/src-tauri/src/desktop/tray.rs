@@ -1,79 +1,213 @@
 use tauri::AppHandle;
 
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+use std::collections::HashMap;
+
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
 use tauri::{
     image::Image,
-    menu::{MenuBuilder, MenuItem, PredefinedMenuItem},
+    menu::{Menu, MenuBuilder, MenuItem, PredefinedMenuItem},
     tray::{MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent},
     Manager, Emitter,
 };
 
 use crate::desktop::{toggle_editor_window, toggle_quicknote_window};
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+use crate::desktop::{toggle_quickai_window, HotkeyConfig, get_registered_shortcuts};
 
+// Stable id of the tray icon, used to look it up for rebuild / teardown.
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
-pub fn setup_system_tray(app: &AppHandle) -> Result<TrayIcon, Box<dyn std::error::Error>> {
-    let icon_bytes = include_bytes!("../../icons/32x32.png");
-    let image = Image::from_bytes(icon_bytes)?;
-    
-    // Create system tray menu
-    let quick_note_item = MenuItem::with_id(app, "quicknote", "Quick Note", true, None::<&str>)?;
+const TRAY_ID: &str = "blinko-tray";
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+const TRAY_TOOLTIP: &str = "Blinko - Quick Note";
+
+// Humanize a single canonical shortcut step ("SC:space" -> "Shift+Ctrl+Space")
+// for display as a menu accelerator.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn humanize_step(step: &str) -> String {
+    let Some((letters, key)) = step.split_once(':') else {
+        return step.to_string();
+    };
+    let mut parts: Vec<&str> = Vec::new();
+    for ch in letters.chars() {
+        parts.push(match ch {
+            'A' => "Alt",
+            'S' => "Shift",
+            'C' => "Ctrl",
+            'M' => "Cmd",
+            _ => continue,
+        });
+    }
+    let mut key_disp = key.to_string();
+    if let Some(first) = key_disp.get_mut(0..1) {
+        first.make_ascii_uppercase();
+    }
+    let mut out = parts.join("+");
+    if !out.is_empty() {
+        out.push('+');
+    }
+    out.push_str(&key_disp);
+    out
+}
+
+// Human-readable accelerator for a command, pulled from the registered-shortcut
+// map. Chord steps are joined with ", " (e.g. "Alt+Space, N").
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn accelerator_for(command: &str, shortcuts: &HashMap<String, String>) -> Option<String> {
+    shortcuts
+        .iter()
+        .find(|(_, bound)| bound.as_str() == command)
+        .map(|(canonical, _)| {
+            canonical
+                .split(' ')
+                .map(humanize_step)
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+}
+
+// Build the tray menu from the current hotkey config: actionable items carry
+// their registered accelerator, and Quick AI only appears when AI is enabled.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn build_tray_menu(app: &AppHandle, config: &HotkeyConfig) -> tauri::Result<Menu<tauri::Wry>> {
+    let shortcuts = get_registered_shortcuts();
+
+    let quick_note_item = MenuItem::with_id(
+        app,
+        "quicknote",
+        "Quick Note",
+        true,
+        accelerator_for("quicknote", &shortcuts).as_deref(),
+    )?;
     let separator1 = PredefinedMenuItem::separator(app)?;
     let toggle_item = MenuItem::with_id(app, "toggle", "Show/Hide Window", true, None::<&str>)?;
     let settings_item = MenuItem::with_id(app, "settings", "Settings", true, None::<&str>)?;
     let separator2 = PredefinedMenuItem::separator(app)?;
     let quit_item = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
-    
-    let tray_menu = MenuBuilder::new(app)
-        .items(&[
-            &quick_note_item,
-            &separator1,
-            &toggle_item,
-            &settings_item,
-            &separator2,
-            &quit_item,
-        ])
-        .build()?;
-    
-    let tray_icon = TrayIconBuilder::with_id("blinko-tray")
+
+    let mut builder = MenuBuilder::new(app).item(&quick_note_item);
+
+    if config.ai_enabled {
+        let quick_ai_item = MenuItem::with_id(
+            app,
+            "quickai",
+            "Quick AI",
+            true,
+            accelerator_for("quickai", &shortcuts).as_deref(),
+        )?;
+        builder = builder.item(&quick_ai_item);
+    }
+
+    builder
+        .item(&separator1)
+        .item(&toggle_item)
+        .item(&settings_item)
+        .item(&separator2)
+        .item(&quit_item)
+        .build()
+}
+
+// Shared menu-event handler so the initial tray and any rebuilt tray behave
+// identically.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn handle_tray_menu_event(app: &AppHandle, id: &str) {
+    match id {
+        "quicknote" => {
+            let _ = toggle_quicknote_window(app.clone());
+        }
+        "quickai" => {
+            let _ = toggle_quickai_window(app.clone());
+        }
+        "toggle" => {
+            let _ = toggle_editor_window(app.clone());
+        }
+        "settings" => {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.show();
+                let _ = window.set_focus();
+                let _ = window.emit("navigate-to-settings", ());
+            }
+        }
+        "quit" => {
+            // Flush geometry for every quick window before tearing the process
+            // down, since exit() never fires WindowEvent::CloseRequested.
+            crate::desktop::save_all_window_states(app);
+            app.exit(0);
+        }
+        _ => {}
+    }
+}
+
+// Create the tray icon with the given menu and wire up the event handlers.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn attach_tray(app: &AppHandle, menu: Menu<tauri::Wry>) -> tauri::Result<TrayIcon> {
+    let icon_bytes = include_bytes!("../../icons/32x32.png");
+    let image = Image::from_bytes(icon_bytes)?;
+
+    TrayIconBuilder::with_id(TRAY_ID)
         .icon(image)
-        .menu(&tray_menu)
-        .tooltip("Blinko - Quick Note")
+        .menu(&menu)
+        .tooltip(TRAY_TOOLTIP)
         .on_tray_icon_event(|tray, event| {
-            match event {
-                TrayIconEvent::Click {
-                    button: MouseButton::Left,
-                    button_state: MouseButtonState::Up,
-                    ..
-                } => {
-                    // Left click to toggle window visibility
-                    let app = tray.app_handle();
-                    let _ = toggle_editor_window(app.clone());
-                }
-                _ => {}
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                // Left click to toggle window visibility
+                let app = tray.app_handle();
+                let _ = toggle_editor_window(app.clone());
             }
         })
         .on_menu_event(|app, event| {
-            match event.id().as_ref() {
-                "quicknote" => {
-                    let _ = toggle_quicknote_window(app.clone());
-                }
-                "toggle" => {
-                    let _ = toggle_editor_window(app.clone());
-                }
-                "settings" => {
-                    if let Some(window) = app.get_webview_window("main") {
-                        let _ = window.show();
-                        let _ = window.set_focus();
-                        let _ = window.emit("navigate-to-settings", ());
-                    }
-                }
-                "quit" => {
-                    app.exit(0);
-                }
-                _ => {}
-            }
+            handle_tray_menu_event(app, event.id().as_ref());
         })
-        .build(app)?;
+        .build(app)
+}
 
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+pub fn setup_system_tray(app: &AppHandle) -> Result<TrayIcon, Box<dyn std::error::Error>> {
+    let config = HotkeyConfig::default();
+    let menu = build_tray_menu(app, &config)?;
+    let tray_icon = attach_tray(app, menu)?;
     Ok(tray_icon)
-}
\ No newline at end of file
+}
+
+// Rebuild the tray menu at runtime from the supplied config. The icon itself is
+// created or torn down based on `system_tray_enabled`, so toggling it off in
+// settings removes the tray without a restart; when on, an existing tray has
+// its menu swapped in place and a missing one is created fresh.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+pub fn rebuild_tray_menu(app: &AppHandle, config: &HotkeyConfig) -> Result<(), Box<dyn std::error::Error>> {
+    if !config.system_tray_enabled {
+        let _ = app.remove_tray_by_id(TRAY_ID);
+        println!("System tray disabled, icon removed");
+        return Ok(());
+    }
+
+    let menu = build_tray_menu(app, config)?;
+    if let Some(tray) = app.tray_by_id(TRAY_ID) {
+        tray.set_menu(Some(menu))?;
+        tray.set_tooltip(Some(TRAY_TOOLTIP))?;
+        println!("System tray menu rebuilt");
+    } else {
+        attach_tray(app, menu)?;
+        println!("System tray created");
+    }
+
+    Ok(())
+}
+
+/// Regenerate the tray menu from the frontend's current hotkey configuration.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+#[tauri::command]
+pub fn update_tray_menu(app: AppHandle, config: HotkeyConfig) -> Result<(), String> {
+    rebuild_tray_menu(&app, &config).map_err(|e| format!("Failed to update tray menu: {}", e))
+}
+
+#[cfg(any(target_os = "android", target_os = "ios"))]
+#[tauri::command]
+pub fn update_tray_menu(_app: AppHandle, _config: crate::desktop::HotkeyConfig) -> Result<(), String> {
+    Err("System tray not supported on mobile".to_string())
+}
@@ -1,9 +1,44 @@
 use tauri::{AppHandle, Manager, Emitter, WebviewWindowBuilder, WebviewUrl, Runtime, WindowEvent};
+use serde::{Deserialize, Serialize};
 
 // QuickTool window dimensions - defined once for consistency
 pub const QUICKTOOL_WIDTH: f64 = 190.0;
 pub const QUICKTOOL_HEIGHT: f64 = 35.0;
 
+/// How a window's chrome is drawn.
+///
+/// * `Native` keeps the OS-drawn titlebar and controls.
+/// * `Borderless` removes all chrome (the chromeless look the quick popovers use).
+/// * `CustomOverlay` is frameless but installs an app-drawn drag region — and,
+///   on macOS, leaves the inset traffic-light buttons visible — so the main
+///   window can present unified custom chrome across platforms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum DecorationMode {
+    #[default]
+    Native,
+    Borderless,
+    CustomOverlay,
+}
+
+/// How a frameless window presents its titlebar / window controls.
+///
+/// Every window is built with `.decorations(false)`, so there is no OS-drawn
+/// control cluster or drag region. These variants pick what we install on top:
+///
+/// * `None` keeps the window completely chromeless (the quicktool popover).
+/// * `Overlay` installs an app-drawn close/minimize cluster plus a top drag
+///   strip on every platform — used by quicknote/quickai.
+/// * `OverlayInset` keeps the app-drawn cluster on Windows/Linux but, on macOS,
+///   leaves the native inset traffic-light buttons visible instead — used by
+///   the main window so it matches the platform look.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum TitlebarStyle {
+    #[default]
+    None,
+    Overlay,
+    OverlayInset,
+}
+
 /// Configuration for quick windows
 struct QuickWindowConfig {
     label: &'static str,
@@ -13,6 +48,70 @@ struct QuickWindowConfig {
     height: f64,
     resizable: bool,
     skip_taskbar: bool,
+    // Keep the window present on every virtual desktop / Space so the global
+    // shortcut summons it on whatever desktop is currently active.
+    visible_on_all_workspaces: bool,
+    // Which overlay titlebar (drag region + control cluster) to install after
+    // build; quicktool keeps its chromeless look with `None`.
+    titlebar: TitlebarStyle,
+}
+
+// Fraction of the active monitor's full logical size (taskbar/dock areas are
+// not subtracted) used for the main window's default size, so it scales with
+// the display instead of a fixed 1920×1080.
+const DEFAULT_MAIN_SIZE_FRACTION: f64 = 0.75;
+
+// The monitor the user is most likely looking at: the one under the cursor,
+// falling back to the primary monitor.
+fn active_monitor<R: Runtime>(app: &AppHandle<R>) -> Option<tauri::Monitor> {
+    if let Ok(pos) = app.cursor_position() {
+        if let Ok(Some(monitor)) = app.monitor_from_point(pos.x, pos.y) {
+            return Some(monitor);
+        }
+    }
+    app.primary_monitor().ok().flatten()
+}
+
+// A monitor's logical size (physical pixels divided by its scale factor).
+fn monitor_logical_size(monitor: &tauri::Monitor) -> (f64, f64) {
+    let scale = monitor.scale_factor();
+    let size = monitor.size();
+    (size.width as f64 / scale, size.height as f64 / scale)
+}
+
+/// The default main-window size: a fraction of the active monitor's full
+/// logical size (its work area is not used, so taskbars and docks are not
+/// excluded), so HiDPI and small displays both get a sensibly-sized window
+/// instead of a hardcoded resolution.
+pub fn default_main_window_size<R: Runtime>(app: &AppHandle<R>) -> Option<(f64, f64)> {
+    let monitor = active_monitor(app)?;
+    let (w, h) = monitor_logical_size(monitor.as_ref());
+    Some((w * DEFAULT_MAIN_SIZE_FRACTION, h * DEFAULT_MAIN_SIZE_FRACTION))
+}
+
+// Center a window on the active monitor, accounting for its position so the
+// window lands on the display under the cursor rather than wherever the OS
+// would otherwise place a frameless popover.
+fn center_on_active_monitor<R: Runtime>(app: &AppHandle<R>, window: &tauri::WebviewWindow<R>) {
+    let Some(monitor) = active_monitor(app) else {
+        return;
+    };
+    let (mon_w, mon_h) = monitor_logical_size(&monitor);
+    let scale = monitor.scale_factor();
+    let mon_pos = monitor.position();
+    let (mon_x, mon_y) = (mon_pos.x as f64 / scale, mon_pos.y as f64 / scale);
+
+    let (win_w, win_h) = match window.inner_size() {
+        Ok(size) => (size.width as f64 / scale, size.height as f64 / scale),
+        Err(_) => return,
+    };
+
+    let x = mon_x + (mon_w - win_w) / 2.0;
+    let y = mon_y + (mon_h - win_h) / 2.0;
+    let position = tauri::Position::Logical(tauri::LogicalPosition::new(x, y));
+    if let Err(e) = window.set_position(position) {
+        eprintln!("Failed to center {} on active monitor: {}", window.label(), e);
+    }
 }
 
 /// Helper function to create a quick window with common settings
@@ -35,13 +134,49 @@ fn create_quick_window<R: Runtime>(
         .build()
         .map_err(|e| format!("Failed to create {} window: {}", config.label, e))?;
 
-    // Handle window close event - hide instead of close
+    // Keep spotlight-style popovers on every workspace so the global shortcut
+    // can summon them regardless of which desktop is active.
+    if config.visible_on_all_workspaces {
+        if let Err(e) = window.set_visible_on_all_workspaces(true) {
+            eprintln!("Failed to set {} visible on all workspaces: {}", config.label, e);
+        }
+    }
+
+    // Install a draggable overlay titlebar / control cluster for windows that
+    // ask for one, since `.decorations(false)` leaves them with no way to move
+    // or close the window otherwise.
+    if let Err(e) = install_overlay_titlebar(&window, config.titlebar) {
+        eprintln!("Failed to install {} titlebar: {}", config.label, e);
+    }
+
+    // Restore any persisted geometry for this window using its per-label flag
+    // set (quicknote persists SIZE, quicktool nothing, etc.).
+    crate::desktop::restore_window_state_default(app, config.label);
+
+    // Center the popover on the monitor under the cursor so it opens where the
+    // user is working on multi-monitor setups.
+    center_on_active_monitor(app, &window);
+
+    // Handle window close event - hide instead of close, persisting geometry
+    // first so a resized popover reopens at the same size.
     let window_clone = window.clone();
+    let label = config.label;
+    let save_app = app.clone();
     window.on_window_event(move |event| {
-        if let WindowEvent::CloseRequested { api, .. } = event {
-            api.prevent_close();
-            let _ = window_clone.hide();
-            println!("{} window hidden", config.label);
+        match event {
+            WindowEvent::CloseRequested { api, .. } => {
+                crate::desktop::save_window_state_default(&save_app, label);
+                api.prevent_close();
+                let _ = window_clone.hide();
+                println!("{} window hidden", label);
+            }
+            WindowEvent::Resized(_) => {
+                // The hotkey-driven path only ever hides this window, which never
+                // fires CloseRequested, so a resize has to be saved as it happens
+                // or it's lost the next time the popover is dismissed that way.
+                crate::desktop::save_window_state_default(&save_app, label);
+            }
+            _ => {}
         }
     });
 
@@ -53,8 +188,19 @@ fn toggle_window<R: Runtime>(app: &AppHandle<R>, window_label: &str) -> Result<(
     if let Some(window) = app.get_webview_window(window_label) {
         match window.is_visible() {
             Ok(true) => {
-                let _ = window.hide();
-                println!("{} window hidden", window_label);
+                // A window can be "visible" yet live on another virtual desktop /
+                // Space, in which case the hotkey would appear to do nothing. If
+                // it isn't the focused window, re-assert it on the active desktop
+                // and bring it forward instead of hiding it.
+                if window.is_focused().unwrap_or(false) {
+                    let _ = window.hide();
+                    println!("{} window hidden", window_label);
+                } else {
+                    let _ = window.set_visible_on_all_workspaces(true);
+                    let _ = window.show();
+                    let _ = window.set_focus();
+                    println!("{} window re-shown on active workspace", window_label);
+                }
                 Ok(())
             }
             Ok(false) | Err(_) => {
@@ -100,10 +246,15 @@ pub fn toggle_editor_window<R: tauri::Runtime>(app: AppHandle<R>) -> Result<(),
 #[tauri::command]
 pub fn resize_quicknote_window<R: tauri::Runtime>(app: AppHandle<R>, height: f64) -> Result<(), String> {
     if let Some(window) = app.get_webview_window("quicknote") {
-        let width = 600.0;
-        // Limit max height to 600, min height to 100
-        let constrained_height = height.max(100.0).min(600.0);
-        
+        // Clamp against the active monitor so the popover never exceeds a small
+        // screen: width stays at 600 but no wider than the display, and height
+        // stays in the 100–600 band capped at the screen height.
+        let (max_w, max_h) = active_monitor(&app)
+            .map(|m| monitor_logical_size(&m))
+            .unwrap_or((f64::INFINITY, f64::INFINITY));
+        let width = 600.0_f64.min(max_w);
+        let constrained_height = height.max(100.0).min(600.0).min(max_h);
+
         // Use Tauri 2 Size
         let size = tauri::Size::Logical(tauri::LogicalSize::new(width, constrained_height));
         window.set_size(size)
@@ -132,6 +283,8 @@ pub fn toggle_quicknote_window<R: tauri::Runtime>(app: AppHandle<R>) -> Result<(
         height: 150.0,
         resizable: true,
         skip_taskbar: false,
+        visible_on_all_workspaces: true,
+        titlebar: TitlebarStyle::Overlay,
     };
 
     create_quick_window(&app, config)
@@ -140,10 +293,14 @@ pub fn toggle_quicknote_window<R: tauri::Runtime>(app: AppHandle<R>) -> Result<(
 #[tauri::command]
 pub fn resize_quickai_window<R: tauri::Runtime>(app: AppHandle<R>, height: f64) -> Result<(), String> {
     if let Some(window) = app.get_webview_window("quickai") {
-        let width = 600.0;
-        // Limit max height to 600, min height to 100 (same as quicknote)
-        let constrained_height = height.max(100.0).min(600.0);
-        
+        // Clamp against the active monitor (same rules as quicknote) so the
+        // popover never overflows a small display.
+        let (max_w, max_h) = active_monitor(&app)
+            .map(|m| monitor_logical_size(&m))
+            .unwrap_or((f64::INFINITY, f64::INFINITY));
+        let width = 600.0_f64.min(max_w);
+        let constrained_height = height.max(100.0).min(600.0).min(max_h);
+
         // Use Tauri 2 Size
         let size = tauri::Size::Logical(tauri::LogicalSize::new(width, constrained_height));
         window.set_size(size)
@@ -172,6 +329,8 @@ pub fn toggle_quickai_window<R: tauri::Runtime>(app: AppHandle<R>) -> Result<(),
         height: 125.0,
         resizable: true,
         skip_taskbar: false,
+        visible_on_all_workspaces: true,
+        titlebar: TitlebarStyle::Overlay,
     };
 
     create_quick_window(&app, config)
@@ -220,11 +379,204 @@ pub fn toggle_quicktool_window<R: tauri::Runtime>(app: AppHandle<R>) -> Result<(
         height: QUICKTOOL_HEIGHT,
         resizable: false,
         skip_taskbar: true,
+        visible_on_all_workspaces: true,
+        titlebar: TitlebarStyle::None,
     };
 
     create_quick_window(&app, config)
 }
 
+// Install a transparent, draggable drag region across the top of a frameless
+// window so the user can still move it without an OS titlebar. We mark a strip
+// with `data-tauri-drag-region`, which Tauri turns into an OS move handle.
+fn install_custom_overlay<R: Runtime>(window: &tauri::WebviewWindow<R>) -> Result<(), String> {
+    let js = r#"
+        (function () {
+            if (document.getElementById('__blinko_drag_region')) return;
+            const bar = document.createElement('div');
+            bar.id = '__blinko_drag_region';
+            bar.setAttribute('data-tauri-drag-region', '');
+            bar.style.cssText =
+                'position:fixed;top:0;left:0;right:0;height:32px;' +
+                'z-index:2147483647;background:transparent;';
+            document.body.appendChild(bar);
+        })();
+    "#;
+    window.eval(js)
+        .map_err(|e| format!("Failed to install drag region: {}", e))?;
+
+    // On macOS keep the inset traffic-light buttons floating over the frameless
+    // window; on Windows the non-client hit-test on the drag region preserves
+    // native snap / aero-shake behavior.
+    #[cfg(target_os = "macos")]
+    {
+        use tauri::TitleBarStyle;
+        let _ = window.set_title_bar_style(TitleBarStyle::Overlay);
+    }
+
+    Ok(())
+}
+
+// Install an overlay titlebar for a frameless window: a top drag strip plus an
+// app-drawn close/minimize cluster wired to the window-control commands. On
+// macOS `OverlayInset` defers to the native inset traffic-light buttons instead
+// of drawing our own cluster.
+pub(crate) fn install_overlay_titlebar<R: Runtime>(
+    window: &tauri::WebviewWindow<R>,
+    style: TitlebarStyle,
+) -> Result<(), String> {
+    if style == TitlebarStyle::None {
+        return Ok(());
+    }
+
+    // On macOS, OverlayInset keeps the native traffic lights floating over the
+    // frameless content rather than drawing our own cluster.
+    #[cfg(target_os = "macos")]
+    let draw_controls = style != TitlebarStyle::OverlayInset;
+    #[cfg(not(target_os = "macos"))]
+    let draw_controls = true;
+
+    #[cfg(target_os = "macos")]
+    if style == TitlebarStyle::OverlayInset {
+        use tauri::TitleBarStyle;
+        let _ = window.set_title_bar_style(TitleBarStyle::Overlay);
+    }
+
+    let label = window.label().to_string();
+    let js = format!(
+        r#"
+        (function () {{
+            if (document.getElementById('__blinko_titlebar')) return;
+            const invoke = window.__TAURI__ && window.__TAURI__.core && window.__TAURI__.core.invoke;
+            const bar = document.createElement('div');
+            bar.id = '__blinko_titlebar';
+            bar.setAttribute('data-tauri-drag-region', '');
+            bar.style.cssText =
+                'position:fixed;top:0;left:0;right:0;height:32px;' +
+                'z-index:2147483647;background:transparent;' +
+                'display:flex;justify-content:flex-end;align-items:center;';
+            if ({draw_controls}) {{
+                const mk = function (id, glyph) {{
+                    const b = document.createElement('button');
+                    b.textContent = glyph;
+                    b.style.cssText =
+                        'width:28px;height:22px;margin:0 2px;border:none;background:transparent;' +
+                        'font-size:13px;line-height:1;cursor:pointer;color:inherit;';
+                    b.addEventListener('click', function () {{
+                        if (invoke) invoke(id, {{ label: '{label}' }});
+                    }});
+                    return b;
+                }};
+                bar.appendChild(mk('minimize_window', '–'));
+                bar.appendChild(mk('maximize_window', '☐'));
+                bar.appendChild(mk('close_window', '×'));
+            }}
+            document.body.appendChild(bar);
+        }})();
+    "#,
+        draw_controls = draw_controls,
+        label = label,
+    );
+
+    window.eval(&js)
+        .map_err(|e| format!("Failed to install overlay titlebar: {}", e))?;
+
+    Ok(())
+}
+
+/// Minimize a window by label. Exposed because quick windows are built with
+/// `.minimizable(false)`, so the app-drawn control cluster drives this instead.
+#[tauri::command]
+pub fn minimize_window<R: tauri::Runtime>(app: AppHandle<R>, label: String) -> Result<(), String> {
+    let window = app.get_webview_window(&label)
+        .ok_or_else(|| format!("{} window not found", label))?;
+    window.minimize()
+        .map_err(|e| format!("Failed to minimize {} window: {}", label, e))
+}
+
+/// Toggle a window's maximized state by label.
+#[tauri::command]
+pub fn maximize_window<R: tauri::Runtime>(app: AppHandle<R>, label: String) -> Result<(), String> {
+    let window = app.get_webview_window(&label)
+        .ok_or_else(|| format!("{} window not found", label))?;
+    if window.is_maximized().unwrap_or(false) {
+        window.unmaximize()
+            .map_err(|e| format!("Failed to unmaximize {} window: {}", label, e))
+    } else {
+        window.maximize()
+            .map_err(|e| format!("Failed to maximize {} window: {}", label, e))
+    }
+}
+
+/// Close a window by label. Quick windows intercept the close request to hide
+/// instead of destroying the window, matching the existing close behavior.
+#[tauri::command]
+pub fn close_window<R: tauri::Runtime>(app: AppHandle<R>, label: String) -> Result<(), String> {
+    let window = app.get_webview_window(&label)
+        .ok_or_else(|| format!("{} window not found", label))?;
+    window.close()
+        .map_err(|e| format!("Failed to close {} window: {}", label, e))
+}
+
+/// Apply a decoration mode to a window handle.
+pub(crate) fn apply_decoration_mode<R: Runtime>(
+    window: &tauri::WebviewWindow<R>,
+    mode: DecorationMode,
+) -> Result<(), String> {
+    match mode {
+        DecorationMode::Native => {
+            window.set_decorations(true)
+                .map_err(|e| format!("Failed to enable decorations: {}", e))?;
+        }
+        DecorationMode::Borderless => {
+            window.set_decorations(false)
+                .map_err(|e| format!("Failed to disable decorations: {}", e))?;
+        }
+        DecorationMode::CustomOverlay => {
+            window.set_decorations(false)
+                .map_err(|e| format!("Failed to disable decorations: {}", e))?;
+            install_custom_overlay(window)?;
+        }
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn set_window_decorations(
+    app: AppHandle,
+    label: String,
+    mode: DecorationMode,
+) -> Result<(), String> {
+    let window = app.get_webview_window(&label)
+        .ok_or_else(|| format!("{} window not found", label))?;
+
+    apply_decoration_mode(&window, mode)?;
+
+    // Persist the chosen mode. It is re-applied only by a restore whose flag
+    // set includes DECORATIONS; the per-window default flag sets used when
+    // windows are built at launch do not include it.
+    crate::desktop::set_window_decoration_mode(&app, &label, mode);
+
+    println!("Set {} decoration mode: {:?}", label, mode);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn set_window_visible_on_all_workspaces<R: tauri::Runtime>(
+    app: AppHandle<R>,
+    label: String,
+    enabled: bool,
+) -> Result<(), String> {
+    if let Some(window) = app.get_webview_window(&label) {
+        window.set_visible_on_all_workspaces(enabled)
+            .map_err(|e| format!("Failed to set visible on all workspaces: {}", e))?;
+        println!("Set {} visible on all workspaces: {}", label, enabled);
+        Ok(())
+    } else {
+        Err(format!("{} window not found", label))
+    }
+}
+
 #[tauri::command]
 pub fn hide_quicktool_window<R: tauri::Runtime>(app: AppHandle<R>) -> Result<(), String> {
     if let Some(window) = app.get_webview_window("quicktool") {
@@ -1,43 +1,97 @@
-use tauri::{AppHandle, Manager};
+use tauri::{AppHandle, Manager, Runtime};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 use crate::desktop::hotkey::WindowConfig;
+use crate::desktop::window::DecorationMode;
 
 const WINDOW_STATE_FILE: &str = "window_state.json";
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct AppWindowState {
-    main_window: Option<WindowConfig>,
-    quicknote_window: Option<WindowConfig>,
-}
+// Labels of every window whose geometry we know how to persist.
+const KNOWN_WINDOW_LABELS: &[&str] = &["main", "quicknote", "quickai", "quicktool"];
+
+/// Bit flags describing which pieces of a window's state should be persisted
+/// and restored. Modelled on the flag set used by the upstream window-state
+/// plugin so callers can opt a given window in or out of remembering each
+/// property independently. `FULLSCREEN` and `VISIBLE` are accepted by
+/// `from_bits`, but capture and restore do not act on them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StateFlags(u32);
 
-impl Default for AppWindowState {
-    fn default() -> Self {
-        Self {
-            main_window: Some(WindowConfig::default()),
-            quicknote_window: None,
+impl StateFlags {
+    pub const SIZE: Self = Self(1 << 0);
+    pub const POSITION: Self = Self(1 << 1);
+    pub const MAXIMIZED: Self = Self(1 << 2);
+    pub const FULLSCREEN: Self = Self(1 << 3);
+    pub const VISIBLE: Self = Self(1 << 4);
+    pub const DECORATIONS: Self = Self(1 << 5);
+
+    /// Every known flag set.
+    pub const fn all() -> Self {
+        Self(
+            Self::SIZE.0
+                | Self::POSITION.0
+                | Self::MAXIMIZED.0
+                | Self::FULLSCREEN.0
+                | Self::VISIBLE.0
+                | Self::DECORATIONS.0,
+        )
+    }
+
+    /// Build a flag set from raw bits, rejecting any bit that isn't a known flag.
+    pub fn from_bits(bits: u32) -> Option<Self> {
+        if bits & !Self::all().0 == 0 {
+            Some(Self(bits))
+        } else {
+            None
         }
     }
+
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+// Default flag sets per window. The main window remembers everything a desktop
+// app normally would; the quick popovers only remember what makes sense for a
+// spotlight-style overlay (they are re-centered/re-positioned on demand).
+fn default_flags_for(label: &str) -> StateFlags {
+    match label {
+        "main" => StateFlags(StateFlags::SIZE.0 | StateFlags::POSITION.0 | StateFlags::MAXIMIZED.0),
+        "quicknote" | "quickai" => StateFlags::SIZE,
+        _ => StateFlags(0),
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct AppWindowState {
+    // Per-window geometry keyed by window label (main, quicknote, quickai, quicktool).
+    #[serde(default)]
+    windows: HashMap<String, WindowConfig>,
 }
 
 // Get window state file path
-fn get_window_state_path(app: &AppHandle) -> Result<PathBuf, String> {
+fn get_window_state_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
     let app_data_dir = app.path().app_data_dir()
         .map_err(|e| format!("Failed to get app data directory: {}", e))?;
-    
-    
+
+
     // Ensure directory exists
     if !app_data_dir.exists() {
         fs::create_dir_all(&app_data_dir)
             .map_err(|e| format!("Failed to create app data directory: {}", e))?;
     }
-    
+
     Ok(app_data_dir.join(WINDOW_STATE_FILE))
 }
 
 // Load window state from file
-pub fn load_window_state(app: &AppHandle) -> AppWindowState {
+pub fn load_window_state<R: Runtime>(app: &AppHandle<R>) -> AppWindowState {
     match get_window_state_path(app) {
         Ok(path) => {
             if path.exists() {
@@ -64,12 +118,12 @@ pub fn load_window_state(app: &AppHandle) -> AppWindowState {
             eprintln!("Failed to get window state path: {}", e);
         }
     }
-    
+
     AppWindowState::default()
 }
 
 // Save window state to file
-pub fn save_window_state(app: &AppHandle, state: &AppWindowState) {
+pub fn save_window_state<R: Runtime>(app: &AppHandle<R>, state: &AppWindowState) {
     match get_window_state_path(app) {
         Ok(path) => {
             match serde_json::to_string_pretty(state) {
@@ -91,112 +145,410 @@ pub fn save_window_state(app: &AppHandle, state: &AppWindowState) {
     }
 }
 
-// Apply window state to main window
-pub fn restore_main_window_state(app: &AppHandle) {
-    let window_state = load_window_state(app);
+// Minimum main-window dimensions. The spotlight-style quick popovers are far
+// smaller (quicknote opens at 150px tall, quicktool at 35px), so they get their
+// own much lower floor via `min_size_for` — otherwise their SIZE would never be
+// persisted.
+const MIN_WINDOW_WIDTH: f64 = 600.0;
+const MIN_WINDOW_HEIGHT: f64 = 300.0;
+const MIN_QUICK_WINDOW_WIDTH: f64 = 100.0;
+const MIN_QUICK_WINDOW_HEIGHT: f64 = 35.0;
 
-    if let Some(window) = app.get_webview_window("main") {
-        if let Some(config) = window_state.main_window {
-            // Only restore if not maximized, otherwise maximize will set the size
-            if !config.maximized {
-                // Use PhysicalSize to ensure exact pixel restoration
-                let size = tauri::Size::Physical(tauri::PhysicalSize::new(config.width as u32, config.height as u32));
-                if let Err(e) = window.set_size(size) {
-                    eprintln!("Failed to restore window size: {}", e);
-                } else {
-                    println!("Restored window size: {}x{}", config.width, config.height);
-                }
+// Smallest size we'll persist for a given window label. The main window keeps
+// the long-standing 600×300 floor; quick popovers use a much lower one so their
+// geometry is actually saved.
+fn min_size_for(label: &str) -> (f64, f64) {
+    match label {
+        "main" => (MIN_WINDOW_WIDTH, MIN_WINDOW_HEIGHT),
+        _ => (MIN_QUICK_WINDOW_WIDTH, MIN_QUICK_WINDOW_HEIGHT),
+    }
+}
 
-                // Center the window after setting size
-                if let Err(e) = window.center() {
-                    eprintln!("Failed to center window: {}", e);
-                } else {
-                    println!("Window centered successfully");
-                }
+// Capture the current state of a window into a WindowConfig, honoring `flags`
+// so only the requested fields are recorded. Returns None when the window is in
+// a state we refuse to persist (minimized, or shrunk below `min_size`), keeping
+// the long-standing minimized/min-size guard as an invariant.
+fn capture_window_config<R: Runtime>(
+    window: &tauri::WebviewWindow<R>,
+    previous: Option<WindowConfig>,
+    flags: StateFlags,
+    min_size: (f64, f64),
+) -> Option<WindowConfig> {
+    if window.is_minimized().unwrap_or(false) {
+        println!("Skipping window state save - window is minimized");
+        return None;
+    }
+
+    let (min_width, min_height) = min_size;
+    let mut config = previous.unwrap_or_default();
+
+    if flags.contains(StateFlags::SIZE) {
+        if let Ok(size) = window.inner_size() {
+            let width = size.width as f64;
+            let height = size.height as f64;
+            if width < min_width || height < min_height {
+                println!("Skipping window state save - size {}x{} below minimum {}x{}",
+                         width, height, min_width, min_height);
+                return None;
             }
+            config.width = width;
+            config.height = height;
+        }
+    }
+
+    if flags.contains(StateFlags::POSITION) {
+        if let Ok(pos) = window.outer_position() {
+            config.x = Some(pos.x);
+            config.y = Some(pos.y);
+        }
+    }
+
+    if flags.contains(StateFlags::MAXIMIZED) {
+        config.maximized = window.is_maximized().unwrap_or(false);
+    }
+
+    Some(config)
+}
+
+// Save the state of a single window label honoring the given flag set.
+pub fn save_window_state_for<R: Runtime>(app: &AppHandle<R>, label: &str, flags: StateFlags) {
+    let Some(window) = app.get_webview_window(label) else {
+        return;
+    };
+
+    let mut window_state = load_window_state(app);
+    let previous = window_state.windows.get(label).cloned();
+
+    if let Some(config) = capture_window_config(&window, previous, flags, min_size_for(label)) {
+        println!("Saved {} window state: {}x{}, pos: {:?}, maximized: {}",
+                 label, config.width, config.height, (config.x, config.y), config.maximized);
+        window_state.windows.insert(label.to_string(), config);
+        save_window_state(app, &window_state);
+    }
+}
+
+// A physical rectangle in screen coordinates.
+#[derive(Debug, Clone, Copy)]
+struct Rect {
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+}
+
+impl Rect {
+    fn right(&self) -> i32 {
+        self.x + self.width
+    }
+    fn bottom(&self) -> i32 {
+        self.y + self.height
+    }
+    // Overlap of this rect with `other`, as (width, height) in pixels.
+    fn overlap(&self, other: &Rect) -> (i32, i32) {
+        let w = (self.right().min(other.right()) - self.x.max(other.x)).max(0);
+        let h = (self.bottom().min(other.bottom()) - self.y.max(other.y)).max(0);
+        (w, h)
+    }
+}
+
+// A window must remain on-screen by at least this much horizontally and by at
+// least the titlebar height vertically, so it can always be grabbed and moved.
+const MIN_VISIBLE_MARGIN: i32 = 48;
+const TITLEBAR_HEIGHT: i32 = 32;
+
+// Enumerate the full bounds of every currently-connected monitor (taskbar/dock
+// areas are not excluded).
+fn connected_monitor_rects<R: Runtime>(app: &AppHandle<R>) -> Vec<Rect> {
+    app.available_monitors()
+        .map(|monitors| {
+            monitors
+                .into_iter()
+                .map(|m| {
+                    let pos = m.position();
+                    let size = m.size();
+                    Rect {
+                        x: pos.x,
+                        y: pos.y,
+                        width: size.width as i32,
+                        height: size.height as i32,
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// The primary monitor's full bounds (not its work area), as a physical Rect. Used instead of
+// `connected_monitor_rects`'s full list when a single, specific monitor is
+// needed as a fallback target.
+fn primary_monitor_rect<R: Runtime>(app: &AppHandle<R>) -> Option<Rect> {
+    let monitor = app.primary_monitor().ok().flatten()?;
+    let pos = monitor.position();
+    let size = monitor.size();
+    Some(Rect {
+        x: pos.x,
+        y: pos.y,
+        width: size.width as i32,
+        height: size.height as i32,
+    })
+}
+
+// Validate a saved config against the currently-connected monitors. If the
+// saved rectangle no longer lands on any visible monitor (e.g. the external
+// display it lived on was unplugged), drop the saved position so the window
+// falls back to centering on the primary monitor (see the POSITION restore
+// branch in `restore_window_state`) and clamp its size to that monitor's full
+// bounds. A config with no saved position gets the same size clamp. Clamping
+// against the primary monitor specifically — rather than the
+// largest dimensions across all connected monitors — matters on a
+// multi-monitor rig with mismatched orientations: taking `max(width)` and
+// `max(height)` independently across e.g. a 1920x1080 primary and a rotated
+// 1080x1920 secondary would clamp to 1920x1920, a size that fits neither
+// actual monitor.
+fn clamp_restored_geometry<R: Runtime>(app: &AppHandle<R>, mut config: WindowConfig) -> WindowConfig {
+    let monitors = connected_monitor_rects(app);
+    if monitors.is_empty() {
+        return config;
+    }
+
+    let mut fallback = config.x.is_none() || config.y.is_none();
 
-            // Restore maximized state
-            if config.maximized {
-                if let Err(e) = window.maximize() {
-                    eprintln!("Failed to maximize window: {}", e);
+    if let (Some(x), Some(y)) = (config.x, config.y) {
+        let saved = Rect {
+            x,
+            y,
+            width: config.width as i32,
+            height: config.height as i32,
+        };
+
+        let on_screen = monitors.iter().any(|m| {
+            let (ow, oh) = saved.overlap(m);
+            ow >= MIN_VISIBLE_MARGIN && oh >= TITLEBAR_HEIGHT
+        });
+
+        if !on_screen {
+            println!(
+                "Saved position ({}, {}) is off-screen on current monitors, re-centering",
+                x, y
+            );
+            config.x = None;
+            config.y = None;
+            fallback = true;
+        }
+    }
+
+    if fallback {
+        if let Some(primary) = primary_monitor_rect(app) {
+            config.width = config.width.min(primary.width as f64);
+            config.height = config.height.min(primary.height as f64);
+        }
+    }
+
+    config
+}
+
+// Center a window on the primary monitor's full bounds specifically, in
+// physical pixels to match the rest of this file's geometry handling.
+// Distinct from `window::center_on_active_monitor`, which deliberately
+// follows the cursor instead — this one backs the "saved position is
+// off-screen" fallback, where the primary monitor is the one guarantee we
+// can make about the resulting layout.
+fn center_on_primary_monitor<R: Runtime>(app: &AppHandle<R>, window: &tauri::WebviewWindow<R>) {
+    let Some(primary) = primary_monitor_rect(app) else {
+        if let Err(e) = window.center() {
+            eprintln!("Failed to center {} window: {}", window.label(), e);
+        }
+        return;
+    };
+
+    let Ok(size) = window.outer_size() else {
+        if let Err(e) = window.center() {
+            eprintln!("Failed to center {} window: {}", window.label(), e);
+        }
+        return;
+    };
+
+    let x = primary.x + (primary.width - size.width as i32) / 2;
+    let y = primary.y + (primary.height - size.height as i32) / 2;
+    let position = tauri::Position::Physical(tauri::PhysicalPosition::new(x, y));
+    if let Err(e) = window.set_position(position) {
+        eprintln!("Failed to center {} window on primary monitor: {}", window.label(), e);
+    }
+}
+
+// Restore the state of a single window label honoring the given flag set. Only
+// fields whose flag is set are applied, so a window that shouldn't remember its
+// position stays centered.
+pub fn restore_window_state<R: Runtime>(app: &AppHandle<R>, label: &str, flags: StateFlags) {
+    // Nothing to restore for a window that persists no geometry (e.g. quicktool).
+    if flags.bits() == 0 {
+        return;
+    }
+
+    let window_state = load_window_state(app);
+
+    let Some(window) = app.get_webview_window(label) else {
+        return;
+    };
+
+    let Some(config) = window_state.windows.get(label).cloned() else {
+        // No saved state, show the window with its default settings.
+        if let Err(e) = window.show() {
+            eprintln!("Failed to show {} window: {}", label, e);
+        }
+        return;
+    };
+
+    // Validate the saved geometry against the monitors that are connected right
+    // now, before we touch set_size/set_position.
+    let config = clamp_restored_geometry(app, config);
+
+    // Only restore the size if we aren't about to maximize, otherwise maximize
+    // determines the final size.
+    let will_maximize = flags.contains(StateFlags::MAXIMIZED) && config.maximized;
+
+    if flags.contains(StateFlags::SIZE) && !will_maximize {
+        // Use PhysicalSize to ensure exact pixel restoration.
+        let size = tauri::Size::Physical(tauri::PhysicalSize::new(config.width as u32, config.height as u32));
+        if let Err(e) = window.set_size(size) {
+            eprintln!("Failed to restore {} window size: {}", label, e);
+        } else {
+            println!("Restored {} window size: {}x{}", label, config.width, config.height);
+        }
+    }
+
+    if flags.contains(StateFlags::POSITION) && !will_maximize {
+        match (config.x, config.y) {
+            (Some(x), Some(y)) => {
+                let position = tauri::Position::Physical(tauri::PhysicalPosition::new(x, y));
+                if let Err(e) = window.set_position(position) {
+                    eprintln!("Failed to restore {} window position: {}", label, e);
                 } else {
-                    println!("Window maximized successfully");
+                    println!("Restored {} window position: ({}, {})", label, x, y);
                 }
             }
-
-            // Show window after restoring state
-            if let Err(e) = window.show() {
-                eprintln!("Failed to show main window: {}", e);
-            } else {
-                println!("Main window shown after state restoration");
+            _ => {
+                // No saved position, or clamp_restored_geometry dropped it as
+                // off-screen: fall back to the primary monitor specifically
+                // rather than `window.center()`, which centers on whatever
+                // monitor the window currently happens to be on.
+                center_on_primary_monitor(app, &window);
             }
+        }
+    } else if !will_maximize {
+        // POSITION flag not set for this window: always center it.
+        if let Err(e) = window.center() {
+            eprintln!("Failed to center {} window: {}", label, e);
+        }
+    }
+
+    if will_maximize {
+        if let Err(e) = window.maximize() {
+            eprintln!("Failed to maximize {} window: {}", label, e);
         } else {
-            // No saved state, show window with default settings
-            if let Err(e) = window.show() {
-                eprintln!("Failed to show main window: {}", e);
-            } else {
-                println!("Main window shown with default settings");
-            }
+            println!("{} window maximized successfully", label);
+        }
+    }
+
+    // Re-apply the persisted decoration mode when requested.
+    if flags.contains(StateFlags::DECORATIONS) {
+        if let Err(e) = crate::desktop::window::apply_decoration_mode(&window, config.decoration_mode) {
+            eprintln!("Failed to restore {} decoration mode: {}", label, e);
         }
     }
+
+    if let Err(e) = window.show() {
+        eprintln!("Failed to show {} window: {}", label, e);
+    } else {
+        println!("{} window shown after state restoration", label);
+    }
 }
 
-// Minimum window dimensions 
-const MIN_WINDOW_WIDTH: f64 = 600.0;
-const MIN_WINDOW_HEIGHT: f64 = 300.0;
+// Persist a window's chosen decoration mode so it survives a restart. Called by
+// the set_window_decorations command; the mode is re-applied by
+// `restore_window_state` whenever DECORATIONS is in the requested flag set. The
+// per-label default flag sets do not include DECORATIONS.
+pub fn set_window_decoration_mode<R: Runtime>(app: &AppHandle<R>, label: &str, mode: DecorationMode) {
+    let mut window_state = load_window_state(app);
+    let config = window_state.windows.entry(label.to_string()).or_default();
+    config.decoration_mode = mode;
+    save_window_state(app, &window_state);
+}
+
+// Apply window state to main window
+pub fn restore_main_window_state<R: Runtime>(app: &AppHandle<R>) {
+    restore_window_state(app, "main", default_flags_for("main"));
+}
 
 // Save current main window state
-pub fn save_main_window_state(app: &AppHandle) {
-    if let Some(window) = app.get_webview_window("main") {
-        let mut window_state = load_window_state(app);
-
-        // Get current window state - only size and maximized state
-        if let (Ok(size), Ok(is_maximized), Ok(is_minimized)) = (
-            window.inner_size(),
-            window.is_maximized(),
-            window.is_minimized()
-        ) {
-            let width = size.width as f64;
-            let height = size.height as f64;
+pub fn save_main_window_state<R: Runtime>(app: &AppHandle<R>) {
+    save_window_state_for(app, "main", default_flags_for("main"));
+}
 
-            // Don't save state if window is minimized or dimensions are too small
-            if is_minimized || width < MIN_WINDOW_WIDTH || height < MIN_WINDOW_HEIGHT {
-                println!("Skipping window state save - minimized: {}, size: {}x{} (min: {}x{})",
-                         is_minimized, width, height, MIN_WINDOW_WIDTH, MIN_WINDOW_HEIGHT);
-                return;
-            }
+/// Restore a window's saved geometry using the per-label default flag set.
+/// Used when building a window (main persists SIZE|POSITION|MAXIMIZED, quicknote
+/// SIZE, quicktool nothing).
+pub fn restore_window_state_default<R: Runtime>(app: &AppHandle<R>, label: &str) {
+    restore_window_state(app, label, default_flags_for(label));
+}
+
+/// Save a window's current geometry using the per-label default flag set.
+pub fn save_window_state_default<R: Runtime>(app: &AppHandle<R>, label: &str) {
+    save_window_state_for(app, label, default_flags_for(label));
+}
 
-            let config = WindowConfig {
-                width,
-                height,
-                x: None,  // Don't save position, always center
-                y: None,  // Don't save position, always center
-                maximized: is_maximized,
-            };
+/// Flush every known window's geometry using its per-label default flag set.
+/// Called from the tray's "Quit" item, since `app.exit()` tears the process
+/// down immediately without ever dispatching `WindowEvent::CloseRequested`.
+pub fn save_all_window_states<R: Runtime>(app: &AppHandle<R>) {
+    for label in KNOWN_WINDOW_LABELS {
+        if app.get_webview_window(label).is_some() {
+            save_window_state_for(app, label, default_flags_for(label));
+        }
+    }
+}
 
-            window_state.main_window = Some(config.clone());
-            save_window_state(app, &window_state);
+/// Command: persist the state of every known window, honoring the flag set the
+/// frontend requests. Bits are decoded with `StateFlags::from_bits`, rejecting
+/// any unknown bit.
+#[tauri::command]
+pub fn save_window_state_cmd(app: AppHandle, flags: u32) -> Result<(), String> {
+    let flags = StateFlags::from_bits(flags)
+        .ok_or_else(|| format!("Invalid state flags: {:#b}", flags))?;
 
-            println!("Saved main window state: {}x{}, maximized: {}",
-                     config.width, config.height, config.maximized);
+    for label in KNOWN_WINDOW_LABELS {
+        if app.get_webview_window(label).is_some() {
+            save_window_state_for(&app, label, flags);
         }
     }
+    Ok(())
+}
+
+/// Command: restore a single window's state from disk, honoring the requested
+/// flag set.
+#[tauri::command]
+pub fn restore_window_state_cmd(app: AppHandle, label: String, flags: u32) -> Result<(), String> {
+    let flags = StateFlags::from_bits(flags)
+        .ok_or_else(|| format!("Invalid state flags: {:#b}", flags))?;
+
+    restore_window_state(&app, &label, flags);
+    Ok(())
 }
 
 // Setup window state monitoring - ONLY for main window
-pub fn setup_window_state_monitoring(app: &AppHandle) {
+pub fn setup_window_state_monitoring<R: Runtime>(app: &AppHandle<R>) {
     // Only monitor the main window for state saving
     if let Some(window) = app.get_webview_window("main") {
         let app_handle = app.clone();
 
         window.on_window_event(move |event| {
             match event {
-                tauri::WindowEvent::Resized(_) => {
-                    // Save state on resize (but only if not minimized and above minimum size)
+                tauri::WindowEvent::Resized(_) | tauri::WindowEvent::Moved(_) => {
+                    // Save state on resize/move (the guard skips minimized/too-small windows).
                     save_main_window_state(&app_handle);
                 }
                 tauri::WindowEvent::CloseRequested { .. } => {
-                    // Save state before closing (but only if not minimized and above minimum size)
+                    // Save state before closing.
                     save_main_window_state(&app_handle);
                 }
                 _ => {}
@@ -207,4 +559,4 @@ pub fn setup_window_state_monitoring(app: &AppHandle) {
     } else {
         eprintln!("Failed to setup window state monitoring: main window not found");
     }
-}
\ No newline at end of file
+}
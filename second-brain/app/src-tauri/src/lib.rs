@@ -66,13 +66,25 @@ pub fn run() {
                 navigate_main_to_ai_with_prompt,
                 toggle_quicktool_window,
                 hide_quicktool_window,
+                set_window_visible_on_all_workspaces,
+                set_window_decorations,
+                minimize_window,
+                maximize_window,
+                close_window,
                 setup_text_selection_monitoring,
+                add_text_selection_binding,
+                remove_text_selection_binding,
+                list_text_selection_bindings,
                 copy_to_clipboard,
                 test_text_selection,
                 check_accessibility_permissions,
                 show_quicktool,
+                set_quicktool_click_through,
                 set_desktop_theme,
-                set_desktop_colors
+                set_desktop_colors,
+                save_window_state_cmd,
+                restore_window_state_cmd,
+                update_tray_menu
             ])
             .setup(|app| {
                 #[cfg(not(any(target_os = "android", target_os = "ios")))]